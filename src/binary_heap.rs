@@ -0,0 +1,199 @@
+//! Priority queue
+
+use crate::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+
+/// Selects whether the greatest or the least element is on top.
+pub trait Kind {
+    /// Ordering of a parent relative to its children.
+    const ORDERING: Ordering;
+}
+/// Greatest element on top.
+pub enum Max {}
+impl Kind for Max {
+    const ORDERING: Ordering = Ordering::Greater;
+}
+/// Least element on top.
+pub enum Min {}
+impl Kind for Min {
+    const ORDERING: Ordering = Ordering::Less;
+}
+
+pub struct BinaryHeap<T, K, const N: usize> {
+    data: Vec<T, N>,
+    _kind: PhantomData<K>,
+}
+impl<T, K, const N: usize> Default for BinaryHeap<T, K, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, K, const N: usize> BinaryHeap<T, K, N> {
+    pub const fn new() -> Self {
+        BinaryHeap {
+            data: Vec::new(),
+            _kind: PhantomData,
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+    /// Elements in arbitrary order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+    pub fn into_vec(self) -> Vec<T, N> {
+        self.data
+    }
+}
+impl<T: Ord, K: Kind, const N: usize> BinaryHeap<T, K, N> {
+    /// Whether `a` belongs above `b`.
+    #[inline]
+    fn above(a: &T, b: &T) -> bool {
+        a.cmp(b) == K::ORDERING
+    }
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if !Self::above(&self.data[pos], &self.data[parent]) {
+                break;
+            }
+            self.data.swap(pos, parent);
+            pos = parent;
+        }
+    }
+    fn sift_down_range(data: &mut [T], mut pos: usize, end: usize) {
+        loop {
+            let mut child = 2 * pos + 1;
+            if child >= end {
+                break;
+            }
+            if child + 1 < end && Self::above(&data[child + 1], &data[child]) {
+                child += 1;
+            }
+            if !Self::above(&data[child], &data[pos]) {
+                break;
+            }
+            data.swap(pos, child);
+            pos = child;
+        }
+    }
+    fn sift_down(&mut self, pos: usize) {
+        let len = self.len();
+        Self::sift_down_range(&mut self.data, pos, len);
+    }
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        self.data.push(item)?;
+        self.sift_up(self.len() - 1);
+        Ok(())
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        self.data.swap(0, len - 1);
+        let item = self.data.pop();
+        self.sift_down(0);
+        item
+    }
+    /// Mutable access to the top element, the heap is restored when the guard drops.
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, K, N>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekMut { heap: self })
+        }
+    }
+    /// Sorted so that the top element comes last:
+    /// ascending for `Max`, descending for `Min`.
+    pub fn into_sorted_vec(self) -> Vec<T, N> {
+        let mut data = self.data;
+        for end in (1..data.len()).rev() {
+            data.swap(0, end);
+            Self::sift_down_range(&mut data, 0, end);
+        }
+        data
+    }
+    /// Removes elements in heap order, clearing the heap even if not fully consumed.
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, T, K, N> {
+        DrainSorted { heap: self }
+    }
+}
+
+pub struct PeekMut<'a, T: Ord, K: Kind, const N: usize> {
+    heap: &'a mut BinaryHeap<T, K, N>,
+}
+impl<'a, T: Ord, K: Kind, const N: usize> PeekMut<'a, T, K, N> {
+    pub fn pop(this: Self) -> T {
+        let this = ManuallyDrop::new(this);
+        match unsafe { core::ptr::read(&this.heap) }.pop() {
+            Some(item) => item,
+            None => unreachable!(),
+        }
+    }
+}
+impl<T: Ord, K: Kind, const N: usize> Deref for PeekMut<'_, T, K, N> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.heap.data[0]
+    }
+}
+impl<T: Ord, K: Kind, const N: usize> DerefMut for PeekMut<'_, T, K, N> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.heap.data[0]
+    }
+}
+impl<T: Ord, K: Kind, const N: usize> Drop for PeekMut<'_, T, K, N> {
+    fn drop(&mut self) {
+        self.heap.sift_down(0);
+    }
+}
+
+pub struct DrainSorted<'a, T: Ord, K: Kind, const N: usize> {
+    heap: &'a mut BinaryHeap<T, K, N>,
+}
+impl<T: Ord, K: Kind, const N: usize> Iterator for DrainSorted<'_, T, K, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.heap.pop()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.heap.len(), Some(self.heap.len()))
+    }
+}
+impl<T: Ord, K: Kind, const N: usize> Drop for DrainSorted<'_, T, K, N> {
+    fn drop(&mut self) {
+        self.heap.clear();
+    }
+}
+
+impl<T: fmt::Debug, K, const N: usize> fmt::Debug for BinaryHeap<T, K, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
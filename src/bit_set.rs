@@ -0,0 +1,176 @@
+//! Bit set
+
+use core::fmt;
+
+type Word = u32;
+const BITS: usize = Word::BITS as usize;
+
+/// Set of integers in `0..WORDS * 32`, one bit per value.
+///
+/// `WORDS` counts `u32` words, not bits: the capacity is `WORDS * 32` bits,
+/// so `BitSet<2>` holds the values `0..64`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitSet<const WORDS: usize> {
+    words: [Word; WORDS],
+}
+impl<const WORDS: usize> Default for BitSet<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const WORDS: usize> BitSet<WORDS> {
+    const CAPACITY: usize = WORDS * BITS;
+    pub const fn new() -> Self {
+        BitSet { words: [0; WORDS] }
+    }
+    /// Builds a set in a `const` context, panics if a bit is out of range.
+    pub const fn from_bits(bits: &[usize]) -> Self {
+        let mut words = [0; WORDS];
+        let mut i = 0;
+        while i < bits.len() {
+            let bit = bits[i];
+            assert!(bit < Self::CAPACITY, "bit out of range");
+            words[bit / BITS] |= 1 << (bit % BITS);
+            i += 1;
+        }
+        BitSet { words }
+    }
+    pub const fn from_words(words: [Word; WORDS]) -> Self {
+        BitSet { words }
+    }
+    pub fn as_words(&self) -> &[Word; WORDS] {
+        &self.words
+    }
+    /// Number of values the set can hold, `WORDS * 32`.
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn len(&self) -> usize {
+        self.count_ones()
+    }
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+    pub fn clear(&mut self) {
+        self.words = [0; WORDS];
+    }
+    pub const fn contains(&self, bit: usize) -> bool {
+        bit < Self::CAPACITY && self.words[bit / BITS] & (1 << (bit % BITS)) != 0
+    }
+    /// Returns `Ok(false)` if `bit` was already set, `Err(bit)` if it is out of range.
+    pub fn insert(&mut self, bit: usize) -> Result<bool, usize> {
+        if bit >= Self::CAPACITY {
+            return Err(bit);
+        }
+        let was = self.contains(bit);
+        self.words[bit / BITS] |= 1 << (bit % BITS);
+        Ok(!was)
+    }
+    pub fn remove(&mut self, bit: usize) -> bool {
+        if !self.contains(bit) {
+            return false;
+        }
+        self.words[bit / BITS] &= !(1 << (bit % BITS));
+        true
+    }
+    /// Least value in the set.
+    pub fn first(&self) -> Option<usize> {
+        self.iter().next()
+    }
+    pub fn iter(&self) -> Iter<'_, WORDS> {
+        Iter {
+            set: self,
+            index: 0,
+            word: self.words.first().copied().unwrap_or(0),
+        }
+    }
+    fn zip_with(&mut self, other: &Self, f: impl Fn(Word, Word) -> Word) {
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a = f(*a, *b);
+        }
+    }
+    pub fn union_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a | b);
+    }
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a & b);
+    }
+    pub fn difference_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a & !b);
+    }
+    pub fn symmetric_difference_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a ^ b);
+    }
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = *self;
+        set.union_with(other);
+        set
+    }
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut set = *self;
+        set.intersect_with(other);
+        set
+    }
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut set = *self;
+        set.difference_with(other);
+        set
+    }
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        let mut set = *self;
+        set.symmetric_difference_with(other);
+        set
+    }
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .all(|(a, b)| a & b == 0)
+    }
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .all(|(a, b)| a & !b == 0)
+    }
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+}
+
+/// Set bits in ascending order.
+pub struct Iter<'a, const WORDS: usize> {
+    set: &'a BitSet<WORDS>,
+    index: usize,
+    /// Bits of `words[index]` not yielded yet.
+    word: Word,
+}
+impl<const WORDS: usize> Iterator for Iter<'_, WORDS> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            self.index += 1;
+            self.word = *self.set.words.get(self.index)?;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.index * BITS + bit)
+    }
+}
+impl<'a, const WORDS: usize> IntoIterator for &'a BitSet<WORDS> {
+    type Item = usize;
+    type IntoIter = Iter<'a, WORDS>;
+
+    fn into_iter(self) -> Iter<'a, WORDS> {
+        self.iter()
+    }
+}
+impl<const WORDS: usize> fmt::Debug for BitSet<WORDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
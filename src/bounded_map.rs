@@ -0,0 +1,239 @@
+//! Map that evicts an entry when full
+
+use crate::linear_map::LinearMap;
+use crate::vec::Vec;
+use core::fmt;
+use core::mem;
+
+/// Decides which entry of a full `BoundedMap` is evicted.
+pub trait EvictionPolicy<K, V> {
+    /// Bookkeeping kept for every entry.
+    type Meta;
+    /// Called for a newly inserted entry.
+    fn inserted(&mut self) -> Self::Meta;
+    /// Called when an entry is read or updated through the map.
+    fn touched(&mut self, _meta: &mut Self::Meta) {}
+    /// Returns the index in `entries` of the entry to evict.
+    /// `entries` is never empty.
+    fn victim(&mut self, entries: &[(K, V)], meta: &[Self::Meta]) -> usize;
+}
+
+fn min_index<M: Ord>(meta: &[M]) -> usize {
+    meta.iter()
+        .enumerate()
+        .min_by_key(|(_i, m)| *m)
+        .map(|(i, _m)| i)
+        .unwrap_or(0)
+}
+
+/// Evicts the oldest inserted entry.
+#[derive(Debug, Default)]
+pub struct Fifo {
+    counter: u64,
+}
+impl Fifo {
+    pub const fn new() -> Self {
+        Fifo { counter: 0 }
+    }
+}
+impl<K, V> EvictionPolicy<K, V> for Fifo {
+    type Meta = u64;
+
+    fn inserted(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+    fn victim(&mut self, _entries: &[(K, V)], meta: &[u64]) -> usize {
+        min_index(meta)
+    }
+}
+
+/// Evicts the least recently used entry.
+#[derive(Debug, Default)]
+pub struct Lru {
+    counter: u64,
+}
+impl Lru {
+    pub const fn new() -> Self {
+        Lru { counter: 0 }
+    }
+}
+impl<K, V> EvictionPolicy<K, V> for Lru {
+    type Meta = u64;
+
+    fn inserted(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+    fn touched(&mut self, meta: &mut u64) {
+        self.counter += 1;
+        *meta = self.counter;
+    }
+    fn victim(&mut self, _entries: &[(K, V)], meta: &[u64]) -> usize {
+        min_index(meta)
+    }
+}
+
+/// Evicts the least frequently used entry.
+#[derive(Debug, Default)]
+pub struct Lfu;
+impl Lfu {
+    pub const fn new() -> Self {
+        Lfu
+    }
+}
+impl<K, V> EvictionPolicy<K, V> for Lfu {
+    type Meta = u32;
+
+    fn inserted(&mut self) -> u32 {
+        0
+    }
+    fn touched(&mut self, meta: &mut u32) {
+        *meta = meta.saturating_add(1);
+    }
+    fn victim(&mut self, _entries: &[(K, V)], meta: &[u32]) -> usize {
+        min_index(meta)
+    }
+}
+
+/// Evicts a pseudo-random entry, using xorshift.
+#[derive(Debug)]
+pub struct Random {
+    state: u32,
+}
+impl Random {
+    /// Xorshift gets stuck at zero, so a zero `seed` is replaced by a fixed one.
+    pub const fn new(seed: u32) -> Self {
+        let state = if seed == 0 { 0x9e37_79b9 } else { seed };
+        Random { state }
+    }
+}
+impl<K, V> EvictionPolicy<K, V> for Random {
+    type Meta = ();
+
+    fn inserted(&mut self) {}
+    fn victim(&mut self, entries: &[(K, V)], _meta: &[()]) -> usize {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x as usize % entries.len()
+    }
+}
+
+/// Lets a closure pick the index of the entry to evict.
+pub struct Callback<F>(pub F);
+impl<K, V, F: FnMut(&[(K, V)]) -> usize> EvictionPolicy<K, V> for Callback<F> {
+    type Meta = ();
+
+    fn inserted(&mut self) {}
+    fn victim(&mut self, entries: &[(K, V)], _meta: &[()]) -> usize {
+        (self.0)(entries)
+    }
+}
+
+/// Outcome of `BoundedMap::insert`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Insertion<K, V> {
+    /// The key was new and there was room for it.
+    Inserted,
+    /// The key was present, its old value is returned.
+    Replaced(V),
+    /// The key was new and this entry was evicted to make room.
+    Evicted(K, V),
+    /// The capacity is `0`, the new entry is given back.
+    Rejected(K, V),
+}
+
+pub struct BoundedMap<K, V, P: EvictionPolicy<K, V>, const N: usize> {
+    map: LinearMap<K, V, N>,
+    meta: Vec<P::Meta, N>,
+    policy: P,
+}
+impl<K, V, P: EvictionPolicy<K, V>, const N: usize> BoundedMap<K, V, P, N> {
+    pub const fn new(policy: P) -> Self {
+        BoundedMap {
+            map: LinearMap::new(),
+            meta: Vec::new(),
+            policy,
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+    pub fn policy(&self) -> &P {
+        &self.policy
+    }
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.meta.clear();
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.map.iter().map(|(k, v)| (k, v))
+    }
+    fn swap_remove(&mut self, index: usize) -> (K, V) {
+        self.meta.swap_remove(index);
+        self.map.swap_remove(index)
+    }
+}
+impl<K: PartialEq, V, P: EvictionPolicy<K, V>, const N: usize> BoundedMap<K, V, P, N> {
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+    /// Gets a value without notifying the policy.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key)
+    }
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let i = self.map.get_index(key)?;
+        self.policy.touched(&mut self.meta[i]);
+        Some(&self.map[i].1)
+    }
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let i = self.map.get_index(key)?;
+        self.policy.touched(&mut self.meta[i]);
+        Some(&mut self.map[i].1)
+    }
+    /// Inserts an entry, evicting one chosen by the policy if the map is full.
+    ///
+    /// With a capacity of `0` nothing is stored and the entry is rejected.
+    pub fn insert(&mut self, key: K, value: V) -> Insertion<K, V> {
+        if let Some(i) = self.map.get_index(&key) {
+            self.policy.touched(&mut self.meta[i]);
+            return Insertion::Replaced(mem::replace(&mut self.map[i].1, value));
+        }
+        let evicted = if !self.map.is_empty() && self.len() == self.capacity() {
+            let i = self.policy.victim(&self.map, &self.meta);
+            Some(self.swap_remove(i))
+        } else {
+            None
+        };
+        if let Err((key, value)) = self.map.insert(key, value) {
+            return Insertion::Rejected(key, value);
+        }
+        let pushed = self.meta.push(self.policy.inserted()).is_ok();
+        debug_assert!(pushed);
+        match evicted {
+            Some((key, value)) => Insertion::Evicted(key, value),
+            None => Insertion::Inserted,
+        }
+    }
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let i = self.map.get_index(key)?;
+        Some(self.swap_remove(i).1)
+    }
+}
+impl<K: fmt::Debug, V: fmt::Debug, P: EvictionPolicy<K, V>, const N: usize> fmt::Debug
+    for BoundedMap<K, V, P, N>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
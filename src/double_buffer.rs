@@ -0,0 +1,140 @@
+//! Ping-pong buffers
+//!
+//! Methods take `&mut self`; to share with an interrupt handler,
+//! put the buffer in a `static` behind your own lock.
+
+use crate::vec::Vec;
+use core::fmt;
+
+/// One buffer being filled while the other is consumed.
+pub struct DoubleBuffer<T, const N: usize> {
+    buffers: [Vec<T, N>; 2],
+    /// Index of the buffer being read.
+    read: usize,
+}
+impl<T, const N: usize> Default for DoubleBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> DoubleBuffer<T, N> {
+    pub const fn new() -> Self {
+        DoubleBuffer {
+            buffers: [Vec::new(), Vec::new()],
+            read: 0,
+        }
+    }
+    /// Capacity of each buffer.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+    /// Elements in the write buffer.
+    pub fn len(&self) -> usize {
+        self.buffers[self.read ^ 1].len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn read(&self) -> &Vec<T, N> {
+        &self.buffers[self.read]
+    }
+    pub fn read_mut(&mut self) -> &mut Vec<T, N> {
+        &mut self.buffers[self.read]
+    }
+    pub fn write(&mut self) -> &mut Vec<T, N> {
+        &mut self.buffers[self.read ^ 1]
+    }
+    /// Both sides at once: `(read, write)`.
+    pub fn split(&mut self) -> (&mut Vec<T, N>, &mut Vec<T, N>) {
+        let (a, b) = self.buffers.split_at_mut(1);
+        if self.read == 0 {
+            (&mut a[0], &mut b[0])
+        } else {
+            (&mut b[0], &mut a[0])
+        }
+    }
+    /// The written buffer becomes the read one and the other way round.
+    pub fn swap(&mut self) {
+        self.read ^= 1;
+    }
+}
+impl<T: fmt::Debug, const N: usize> fmt::Debug for DoubleBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DoubleBuffer")
+            .field("read", &self.buffers[self.read])
+            .field("write", &self.buffers[self.read ^ 1])
+            .finish()
+    }
+}
+
+/// Writer and reader each own a buffer and exchange them through a third,
+/// so neither has to wait for the other to finish.
+pub struct TripleBuffer<T, const N: usize> {
+    buffers: [Vec<T, N>; 3],
+    read: usize,
+    write: usize,
+    /// The middle buffer holds data the reader has not taken yet.
+    fresh: bool,
+}
+impl<T, const N: usize> Default for TripleBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> TripleBuffer<T, N> {
+    pub const fn new() -> Self {
+        TripleBuffer {
+            buffers: [Vec::new(), Vec::new(), Vec::new()],
+            read: 0,
+            write: 1,
+            fresh: false,
+        }
+    }
+    fn middle(&self) -> usize {
+        3 - self.read - self.write
+    }
+    /// Capacity of each buffer.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+    /// Elements in the write buffer.
+    pub fn len(&self) -> usize {
+        self.buffers[self.write].len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn read(&self) -> &Vec<T, N> {
+        &self.buffers[self.read]
+    }
+    pub fn write(&mut self) -> &mut Vec<T, N> {
+        &mut self.buffers[self.write]
+    }
+    /// Hands the written buffer over to the reader, replacing any data it has not taken.
+    ///
+    /// The buffer the writer gets back is cleared, so it never holds an older frame.
+    pub fn publish(&mut self) {
+        self.write = self.middle();
+        self.buffers[self.write].clear();
+        self.fresh = true;
+    }
+    /// Takes the last published buffer if there is one, returns whether it did.
+    pub fn update(&mut self) -> bool {
+        if self.fresh {
+            self.read = self.middle();
+            self.fresh = false;
+            true
+        } else {
+            false
+        }
+    }
+}
+impl<T: fmt::Debug, const N: usize> fmt::Debug for TripleBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TripleBuffer")
+            .field("read", &self.buffers[self.read])
+            .field("write", &self.buffers[self.write])
+            .field("fresh", &self.fresh)
+            .finish()
+    }
+}
@@ -0,0 +1,415 @@
+//! Hash Map
+//!
+//! Open addressing with Robin Hood probing and backward shift deletion.
+
+use core::fmt;
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::iter::{FilterMap, Zip};
+use core::mem::{self, MaybeUninit};
+use core::{ptr, slice};
+
+/// 32-bit FNV-1a, the default hasher since it can be built in a `const fn`.
+#[derive(Clone, Copy)]
+pub struct FnvHasher(u32);
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0x811c_9dc5)
+    }
+}
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0 as u64
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u32;
+            self.0 = self.0.wrapping_mul(0x0100_0193);
+        }
+    }
+}
+#[derive(Clone, Copy, Default, Debug)]
+pub struct FnvBuildHasher;
+impl BuildHasher for FnvBuildHasher {
+    type Hasher = FnvHasher;
+
+    fn build_hasher(&self) -> FnvHasher {
+        FnvHasher::default()
+    }
+}
+
+/// Highest bit is always set, so `0` marks an empty slot.
+pub(crate) type HashValue = u32;
+pub(crate) const EMPTY: HashValue = 0;
+
+pub(crate) fn make_hash<K: Hash, S: BuildHasher>(hash_builder: &S, key: &K) -> HashValue {
+    hash_builder.hash_one(key) as HashValue | !(HashValue::MAX >> 1)
+}
+#[inline]
+pub(crate) fn desired(hash: HashValue, capacity: usize) -> usize {
+    hash as usize % capacity
+}
+#[inline]
+pub(crate) fn next(idx: usize, capacity: usize) -> usize {
+    if idx + 1 == capacity {
+        0
+    } else {
+        idx + 1
+    }
+}
+#[inline]
+pub(crate) fn probe_distance(hash: HashValue, idx: usize, capacity: usize) -> usize {
+    let desired = desired(hash, capacity);
+    if idx >= desired {
+        idx - desired
+    } else {
+        capacity - desired + idx
+    }
+}
+
+pub struct FixedHashMap<K, V, const N: usize, S = FnvBuildHasher> {
+    hashes: [HashValue; N],
+    buf: MaybeUninit<[(K, V); N]>,
+    len: usize,
+    hash_builder: S,
+}
+impl<K, V, const N: usize> Default for FixedHashMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K, V, const N: usize> FixedHashMap<K, V, N> {
+    pub const fn new() -> Self {
+        Self::with_hasher(FnvBuildHasher)
+    }
+}
+impl<K, V, const N: usize, S> FixedHashMap<K, V, N, S> {
+    const CAPACITY: usize = N;
+    pub const fn with_hasher(hash_builder: S) -> Self {
+        FixedHashMap {
+            hashes: [EMPTY; N],
+            buf: MaybeUninit::uninit(),
+            len: 0,
+            hash_builder,
+        }
+    }
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn is_full(&self) -> bool {
+        self.len == Self::CAPACITY
+    }
+    fn ptr(&self) -> *mut (K, V) {
+        self.buf.as_ptr() as *mut (K, V)
+    }
+    fn slots(&self) -> &[MaybeUninit<(K, V)>] {
+        unsafe { slice::from_raw_parts(self.buf.as_ptr() as *const MaybeUninit<(K, V)>, N) }
+    }
+    /// Places a key that is known to be absent. The map must not be full.
+    fn insert_new(&mut self, hash: HashValue, key: K, value: V) -> usize {
+        debug_assert!(!self.is_full());
+
+        let mut idx = desired(hash, N);
+        let mut dist = 0;
+        let mut hash = hash;
+        let mut item = (key, value);
+        let mut placed = None;
+        loop {
+            if self.hashes[idx] == EMPTY {
+                unsafe { ptr::write(self.ptr().add(idx), item) };
+                self.hashes[idx] = hash;
+                self.len += 1;
+                return placed.unwrap_or(idx);
+            }
+            let existing = probe_distance(self.hashes[idx], idx, N);
+            if existing < dist {
+                // Robin Hood: the richer element gives up its slot.
+                mem::swap(&mut hash, &mut self.hashes[idx]);
+                item = unsafe { ptr::replace(self.ptr().add(idx), item) };
+                placed.get_or_insert(idx);
+                dist = existing;
+            }
+            idx = next(idx, N);
+            dist += 1;
+        }
+    }
+    fn remove_index(&mut self, idx: usize) -> (K, V) {
+        let item = unsafe { ptr::read(self.ptr().add(idx)) };
+        let mut hole = idx;
+        let mut after = next(hole, N);
+        while self.hashes[after] != EMPTY && probe_distance(self.hashes[after], after, N) > 0 {
+            unsafe { ptr::copy_nonoverlapping(self.ptr().add(after), self.ptr().add(hole), 1) };
+            self.hashes[hole] = self.hashes[after];
+            hole = after;
+            after = next(hole, N);
+        }
+        self.hashes[hole] = EMPTY;
+        self.len -= 1;
+        item
+    }
+    pub fn clear(&mut self) {
+        for idx in 0..Self::CAPACITY {
+            if self.hashes[idx] != EMPTY {
+                self.hashes[idx] = EMPTY;
+                unsafe { ptr::drop_in_place(self.ptr().add(idx)) };
+            }
+        }
+        self.len = 0;
+    }
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self
+                .hashes
+                .iter()
+                .zip(self.slots())
+                .filter_map(occupied as OccupiedFn<'_, K, V>),
+        }
+    }
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let slots = unsafe {
+            slice::from_raw_parts_mut(self.buf.as_mut_ptr() as *mut MaybeUninit<(K, V)>, N)
+        };
+        IterMut {
+            iter: self
+                .hashes
+                .iter()
+                .zip(slots)
+                .filter_map(occupied_mut as OccupiedMutFn<'_, K, V>),
+        }
+    }
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _v)| k)
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_k, v)| v)
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_k, v)| v)
+    }
+}
+impl<K: Hash + Eq, V, const N: usize, S: BuildHasher> FixedHashMap<K, V, N, S> {
+    fn make_hash(&self, key: &K) -> HashValue {
+        make_hash(&self.hash_builder, key)
+    }
+    fn find(&self, hash: HashValue, key: &K) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut idx = desired(hash, N);
+        for dist in 0..Self::CAPACITY {
+            let h = self.hashes[idx];
+            if h == EMPTY || probe_distance(h, idx, N) < dist {
+                return None;
+            }
+            if h == hash && unsafe { &(*self.ptr().add(idx)).0 } == key {
+                return Some(idx);
+            }
+            idx = next(idx, N);
+        }
+        None
+    }
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let idx = self.find(self.make_hash(key), key)?;
+        unsafe { Some(&(*self.ptr().add(idx)).1) }
+    }
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let idx = self.find(self.make_hash(key), key)?;
+        unsafe { Some(&mut (*self.ptr().add(idx)).1) }
+    }
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find(self.make_hash(key), key).is_some()
+    }
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Ok(Some(entry.insert(value))),
+            Entry::Vacant(entry) => entry.insert(value).map(|_| None),
+        }
+    }
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let idx = self.find(self.make_hash(key), key)?;
+        Some(self.remove_index(idx).1)
+    }
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, N, S> {
+        let hash = self.make_hash(&key);
+        if let Some(index) = self.find(hash, &key) {
+            Entry::Occupied(OccupiedEntry { map: self, index })
+        } else {
+            Entry::Vacant(VacantEntry {
+                map: self,
+                hash,
+                key,
+            })
+        }
+    }
+}
+
+pub enum Entry<'a, K, V, const N: usize, S> {
+    Occupied(OccupiedEntry<'a, K, V, N, S>),
+    Vacant(VacantEntry<'a, K, V, N, S>),
+}
+impl<'a, K, V, const N: usize, S> Entry<'a, K, V, N, S> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+    pub fn or_insert(self, default: V) -> Result<&'a mut V, (K, V)> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> Result<&'a mut V, (K, V)> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+impl<'a, K, V: Default, const N: usize, S> Entry<'a, K, V, N, S> {
+    pub fn or_default(self) -> Result<&'a mut V, (K, V)> {
+        self.or_insert_with(V::default)
+    }
+}
+
+pub struct OccupiedEntry<'a, K, V, const N: usize, S> {
+    map: &'a mut FixedHashMap<K, V, N, S>,
+    index: usize,
+}
+impl<'a, K, V, const N: usize, S> OccupiedEntry<'a, K, V, N, S> {
+    pub fn key(&self) -> &K {
+        unsafe { &(*self.map.ptr().add(self.index)).0 }
+    }
+    pub fn get(&self) -> &V {
+        unsafe { &(*self.map.ptr().add(self.index)).1 }
+    }
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { &mut (*self.map.ptr().add(self.index)).1 }
+    }
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { &mut (*self.map.ptr().add(self.index)).1 }
+    }
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.remove_index(self.index)
+    }
+}
+
+pub struct VacantEntry<'a, K, V, const N: usize, S> {
+    map: &'a mut FixedHashMap<K, V, N, S>,
+    hash: HashValue,
+    key: K,
+}
+impl<'a, K, V, const N: usize, S> VacantEntry<'a, K, V, N, S> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+    pub fn into_key(self) -> K {
+        self.key
+    }
+    pub fn insert(self, value: V) -> Result<&'a mut V, (K, V)> {
+        if self.map.is_full() {
+            return Err((self.key, value));
+        }
+        let idx = self.map.insert_new(self.hash, self.key, value);
+        unsafe { Ok(&mut (*self.map.ptr().add(idx)).1) }
+    }
+}
+
+fn occupied<'a, K, V>(
+    (hash, slot): (&HashValue, &'a MaybeUninit<(K, V)>),
+) -> Option<(&'a K, &'a V)> {
+    if *hash == EMPTY {
+        None
+    } else {
+        let (k, v) = unsafe { &*slot.as_ptr() };
+        Some((k, v))
+    }
+}
+fn occupied_mut<'a, K, V>(
+    (hash, slot): (&HashValue, &'a mut MaybeUninit<(K, V)>),
+) -> Option<(&'a K, &'a mut V)> {
+    if *hash == EMPTY {
+        None
+    } else {
+        let (k, v) = unsafe { &mut *slot.as_mut_ptr() };
+        Some((&*k, v))
+    }
+}
+
+type Slots<'a, K, V> = Zip<slice::Iter<'a, HashValue>, slice::Iter<'a, MaybeUninit<(K, V)>>>;
+type OccupiedFn<'a, K, V> = fn((&'a HashValue, &'a MaybeUninit<(K, V)>)) -> Option<(&'a K, &'a V)>;
+pub struct Iter<'a, K, V> {
+    iter: FilterMap<Slots<'a, K, V>, OccupiedFn<'a, K, V>>,
+}
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.iter.next()
+    }
+}
+
+type SlotsMut<'a, K, V> = Zip<slice::Iter<'a, HashValue>, slice::IterMut<'a, MaybeUninit<(K, V)>>>;
+type OccupiedMutFn<'a, K, V> =
+    fn((&'a HashValue, &'a mut MaybeUninit<(K, V)>)) -> Option<(&'a K, &'a mut V)>;
+pub struct IterMut<'a, K, V> {
+    iter: FilterMap<SlotsMut<'a, K, V>, OccupiedMutFn<'a, K, V>>,
+}
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a mut V)> {
+        self.iter.next()
+    }
+}
+
+impl<'a, K, V, const N: usize, S> IntoIterator for &'a FixedHashMap<K, V, N, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+impl<'a, K, V, const N: usize, S> IntoIterator for &'a mut FixedHashMap<K, V, N, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize, S> fmt::Debug for FixedHashMap<K, V, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+impl<K, V, const N: usize, S> Drop for FixedHashMap<K, V, N, S> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
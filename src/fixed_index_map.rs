@@ -0,0 +1,241 @@
+//! Index Map
+//!
+//! Entries are kept densely in insertion order, with a Robin Hood hash table
+//! of indices into them.
+
+use crate::fixed_hash_map::{
+    desired, make_hash, next, probe_distance, FnvBuildHasher, HashValue, EMPTY,
+};
+use crate::vec::Vec;
+use core::borrow::Borrow;
+use core::convert::AsRef;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::mem;
+use core::ops;
+
+#[derive(Clone, Copy)]
+struct Slot {
+    hash: HashValue,
+    index: usize,
+}
+const EMPTY_SLOT: Slot = Slot {
+    hash: EMPTY,
+    index: 0,
+};
+
+pub struct FixedIndexMap<K, V, const N: usize, S = FnvBuildHasher> {
+    entries: Vec<(K, V), N>,
+    indices: [Slot; N],
+    hash_builder: S,
+}
+impl<K, V, const N: usize> Default for FixedIndexMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K, V, const N: usize> FixedIndexMap<K, V, N> {
+    pub const fn new() -> Self {
+        Self::with_hasher(FnvBuildHasher)
+    }
+}
+impl<K, V, const N: usize, S> FixedIndexMap<K, V, N, S> {
+    const CAPACITY: usize = N;
+    pub const fn with_hasher(hash_builder: S) -> Self {
+        FixedIndexMap {
+            entries: Vec::new(),
+            indices: [EMPTY_SLOT; N],
+            hash_builder,
+        }
+    }
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn is_full(&self) -> bool {
+        self.len() == Self::CAPACITY
+    }
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.indices = [EMPTY_SLOT; N];
+    }
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|(k, v)| (k, v))
+    }
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.entries.get_mut(index).map(|(k, v)| (&*k, v))
+    }
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.entries.first().map(|(k, v)| (k, v))
+    }
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.entries.last().map(|(k, v)| (k, v))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(k, _v)| k)
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_k, v)| v)
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.entries.iter_mut().map(|(_k, v)| v)
+    }
+    fn insert_slot(&mut self, mut slot: Slot) {
+        let mut idx = desired(slot.hash, N);
+        let mut dist = 0;
+        loop {
+            if self.indices[idx].hash == EMPTY {
+                self.indices[idx] = slot;
+                return;
+            }
+            let existing = probe_distance(self.indices[idx].hash, idx, N);
+            if existing < dist {
+                mem::swap(&mut slot, &mut self.indices[idx]);
+                dist = existing;
+            }
+            idx = next(idx, N);
+            dist += 1;
+        }
+    }
+    fn remove_slot(&mut self, idx: usize) {
+        let mut hole = idx;
+        let mut after = next(hole, N);
+        while self.indices[after].hash != EMPTY
+            && probe_distance(self.indices[after].hash, after, N) > 0
+        {
+            self.indices[hole] = self.indices[after];
+            hole = after;
+            after = next(hole, N);
+        }
+        self.indices[hole] = EMPTY_SLOT;
+    }
+    /// Finds the slot pointing at entry `index`.
+    fn find_slot_of(&self, hash: HashValue, index: usize) -> usize {
+        let mut idx = desired(hash, N);
+        while self.indices[idx].index != index || self.indices[idx].hash == EMPTY {
+            idx = next(idx, N);
+        }
+        idx
+    }
+}
+impl<K: Hash + Eq, V, const N: usize, S: BuildHasher> FixedIndexMap<K, V, N, S> {
+    fn find_slot(&self, hash: HashValue, key: &K) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut idx = desired(hash, N);
+        for dist in 0..Self::CAPACITY {
+            let slot = self.indices[idx];
+            if slot.hash == EMPTY || probe_distance(slot.hash, idx, N) < dist {
+                return None;
+            }
+            if slot.hash == hash && &self.entries[slot.index].0 == key {
+                return Some(idx);
+            }
+            idx = next(idx, N);
+        }
+        None
+    }
+    pub fn get_index_of(&self, key: &K) -> Option<usize> {
+        let slot = self.find_slot(make_hash(&self.hash_builder, key), key)?;
+        Some(self.indices[slot].index)
+    }
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let i = self.get_index_of(key)?;
+        Some(&self.entries[i].1)
+    }
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let i = self.get_index_of(key)?;
+        Some(&mut self.entries[i].1)
+    }
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_index_of(key).is_some()
+    }
+    /// Inserts at the end, or replaces the value in place if the key exists.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+        let hash = make_hash(&self.hash_builder, &key);
+        if let Some(slot) = self.find_slot(hash, &key) {
+            let i = self.indices[slot].index;
+            Ok(Some(mem::replace(&mut self.entries[i].1, value)))
+        } else {
+            self.entries.push((key, value))?;
+            let index = self.len() - 1;
+            self.insert_slot(Slot { hash, index });
+            Ok(None)
+        }
+    }
+    /// Removes by shifting the later entries down, keeping the order. O(n).
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.remove_entry(key).map(|(_k, v)| v)
+    }
+    pub fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
+        let slot = self.find_slot(make_hash(&self.hash_builder, key), key)?;
+        Some(self.shift_remove_at(slot))
+    }
+    /// Removes by swapping with the last entry, like `LinearMap::remove`.
+    /// O(1), but the last entry takes the removed one's place.
+    pub fn swap_remove(&mut self, key: &K) -> Option<V> {
+        self.swap_remove_entry(key).map(|(_k, v)| v)
+    }
+    pub fn swap_remove_entry(&mut self, key: &K) -> Option<(K, V)> {
+        let slot = self.find_slot(make_hash(&self.hash_builder, key), key)?;
+        Some(self.swap_remove_at(slot))
+    }
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        if index >= self.len() {
+            return None;
+        }
+        let hash = make_hash(&self.hash_builder, &self.entries[index].0);
+        let slot = self.find_slot_of(hash, index);
+        Some(self.swap_remove_at(slot))
+    }
+    fn shift_remove_at(&mut self, slot: usize) -> (K, V) {
+        let index = self.indices[slot].index;
+        self.remove_slot(slot);
+        for slot in self.indices.iter_mut() {
+            if slot.hash != EMPTY && slot.index > index {
+                slot.index -= 1;
+            }
+        }
+        self.entries[index..].rotate_left(1);
+        self.entries.pop().unwrap()
+    }
+    fn swap_remove_at(&mut self, slot: usize) -> (K, V) {
+        let index = self.indices[slot].index;
+        self.remove_slot(slot);
+        let last = self.len() - 1;
+        if index != last {
+            let hash = make_hash(&self.hash_builder, &self.entries[last].0);
+            let moved = self.find_slot_of(hash, last);
+            self.indices[moved].index = index;
+        }
+        self.entries.swap_remove(index)
+    }
+}
+
+impl<K, V, const N: usize, S> ops::Deref for FixedIndexMap<K, V, N, S> {
+    type Target = [(K, V)];
+
+    fn deref(&self) -> &[(K, V)] {
+        self.entries.deref()
+    }
+}
+impl<K, V, const N: usize, S> AsRef<[(K, V)]> for FixedIndexMap<K, V, N, S> {
+    fn as_ref(&self) -> &[(K, V)] {
+        self
+    }
+}
+impl<K, V, const N: usize, S> Borrow<[(K, V)]> for FixedIndexMap<K, V, N, S> {
+    fn borrow(&self) -> &[(K, V)] {
+        &self[..]
+    }
+}
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize, S> fmt::Debug for FixedIndexMap<K, V, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
@@ -0,0 +1,78 @@
+//! Formatting into fixed buffers
+//!
+//! `Vec<u8, N>` and `String<N>` implement `core::fmt::Write` and fail on overflow,
+//! `Truncating` keeps what fits instead.
+
+use crate::storage::Storage;
+use crate::string::String;
+use crate::vec::VecInner;
+use core::fmt;
+
+/// Buffer that `Truncating` can write into.
+pub trait TruncateBuf {
+    /// Appends the longest prefix of `s` that fits, returns whether all of it did.
+    fn push_truncated(&mut self, s: &str) -> bool;
+}
+impl<S: Storage<u8> + ?Sized> TruncateBuf for VecInner<u8, S> {
+    fn push_truncated(&mut self, s: &str) -> bool {
+        let n = s.len().min(self.capacity() - self.len());
+        self.extend_from_slice(&s.as_bytes()[..n]);
+        n == s.len()
+    }
+}
+impl<const N: usize> TruncateBuf for String<N> {
+    fn push_truncated(&mut self, s: &str) -> bool {
+        let mut n = s.len().min(self.remaining());
+        while !s.is_char_boundary(n) {
+            n -= 1;
+        }
+        let _ = self.push_str(&s[..n]);
+        n == s.len()
+    }
+}
+
+/// Writer that drops whatever does not fit and remembers that it did.
+///
+/// A `String` is only cut on char boundaries.
+pub struct Truncating<'a, B: ?Sized> {
+    buf: &'a mut B,
+    truncated: bool,
+}
+impl<'a, B: TruncateBuf + ?Sized> Truncating<'a, B> {
+    pub fn new(buf: &'a mut B) -> Self {
+        Truncating {
+            buf,
+            truncated: false,
+        }
+    }
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+impl<B: TruncateBuf + ?Sized> fmt::Write for Truncating<'_, B> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !self.truncated && !self.buf.push_truncated(s) {
+            self.truncated = true;
+        }
+        Ok(())
+    }
+}
+
+/// Formats into a new `String<N>`, like `format!`.
+///
+/// Returns `Err(core::fmt::Error)` if the output does not fit.
+///
+/// ```
+/// let s = fixed_queue::format_fixed!(16, "{}-{}", 1, 2).unwrap();
+/// assert_eq!(s, "1-2");
+/// ```
+#[macro_export]
+macro_rules! format_fixed {
+    ($n:expr, $($arg:tt)*) => {{
+        let mut s = $crate::String::<{ $n }>::new();
+        match ::core::fmt::Write::write_fmt(&mut s, ::core::format_args!($($arg)*)) {
+            Ok(()) => Ok(s),
+            Err(e) => Err(e),
+        }
+    }};
+}
@@ -0,0 +1,321 @@
+//! Gap Buffer
+//!
+//! Elements before the cursor live at the front of the buffer and elements
+//! after it at the back, so edits at the cursor are O(1) and moving the
+//! cursor costs only the distance moved.
+
+use crate::string::CapacityError;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::{ptr, slice, str};
+
+pub struct GapBuffer<T, const N: usize> {
+    buf: MaybeUninit<[T; N]>,
+    // Start of the gap, which is also the cursor.
+    gap_start: usize,
+    // First element after the gap.
+    gap_end: usize,
+}
+impl<T, const N: usize> Default for GapBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> GapBuffer<T, N> {
+    const CAPACITY: usize = N;
+    pub const fn new() -> Self {
+        GapBuffer {
+            buf: MaybeUninit::uninit(),
+            gap_start: 0,
+            gap_end: N,
+        }
+    }
+    fn ptr(&self) -> *mut T {
+        self.buf.as_ptr() as *mut T
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn len(&self) -> usize {
+        self.gap_start + (Self::CAPACITY - self.gap_end)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn is_full(&self) -> bool {
+        self.gap_start == self.gap_end
+    }
+    pub fn cursor(&self) -> usize {
+        self.gap_start
+    }
+    /// Moves the gap so that `cursor` elements precede it.
+    ///
+    /// Panics if `cursor > len()`.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        assert!(cursor <= self.len());
+        unsafe {
+            if cursor < self.gap_start {
+                let amt = self.gap_start - cursor;
+                ptr::copy(
+                    self.ptr().add(cursor),
+                    self.ptr().add(self.gap_end - amt),
+                    amt,
+                );
+                self.gap_start -= amt;
+                self.gap_end -= amt;
+            } else if cursor > self.gap_start {
+                let amt = cursor - self.gap_start;
+                ptr::copy(
+                    self.ptr().add(self.gap_end),
+                    self.ptr().add(self.gap_start),
+                    amt,
+                );
+                self.gap_start += amt;
+                self.gap_end += amt;
+            }
+        }
+    }
+    pub fn move_left(&mut self) -> bool {
+        if self.gap_start == 0 {
+            return false;
+        }
+        self.set_cursor(self.gap_start - 1);
+        true
+    }
+    pub fn move_right(&mut self) -> bool {
+        if self.gap_end == Self::CAPACITY {
+            return false;
+        }
+        self.set_cursor(self.gap_start + 1);
+        true
+    }
+    /// Inserts before the cursor and advances past the new element.
+    pub fn insert(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        unsafe { ptr::write(self.ptr().add(self.gap_start), value) };
+        self.gap_start += 1;
+        Ok(())
+    }
+    /// Removes the element before the cursor, like backspace.
+    pub fn delete_backward(&mut self) -> Option<T> {
+        if self.gap_start == 0 {
+            return None;
+        }
+        self.gap_start -= 1;
+        unsafe { Some(ptr::read(self.ptr().add(self.gap_start))) }
+    }
+    /// Removes the element after the cursor, like delete.
+    pub fn delete_forward(&mut self) -> Option<T> {
+        if self.gap_end == Self::CAPACITY {
+            return None;
+        }
+        self.gap_end += 1;
+        unsafe { Some(ptr::read(self.ptr().add(self.gap_end - 1))) }
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (before, after) = self.as_slices();
+        if index < before.len() {
+            before.get(index)
+        } else {
+            after.get(index - before.len())
+        }
+    }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (before, after) = self.as_mut_slices();
+        if index < before.len() {
+            before.get_mut(index)
+        } else {
+            after.get_mut(index - before.len())
+        }
+    }
+    /// The elements before and after the cursor.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        unsafe {
+            (
+                slice::from_raw_parts(self.ptr(), self.gap_start),
+                slice::from_raw_parts(self.ptr().add(self.gap_end), N - self.gap_end),
+            )
+        }
+    }
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        unsafe {
+            (
+                slice::from_raw_parts_mut(self.ptr(), self.gap_start),
+                slice::from_raw_parts_mut(self.ptr().add(self.gap_end), N - self.gap_end),
+            )
+        }
+    }
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        let (before, after) = self.as_slices();
+        before.iter().chain(after.iter())
+    }
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        let (before, after) = self.as_mut_slices();
+        before.iter_mut().chain(after.iter_mut())
+    }
+    pub fn clear(&mut self) {
+        unsafe {
+            let (before, after) = self.as_mut_slices();
+            let before: *mut [T] = before;
+            let after: *mut [T] = after;
+            self.gap_start = 0;
+            self.gap_end = N;
+            ptr::drop_in_place(before);
+            ptr::drop_in_place(after);
+        }
+    }
+}
+impl<T: Clone, const N: usize> GapBuffer<T, N> {
+    /// Inserts all of `values` before the cursor, or nothing.
+    pub fn insert_slice(&mut self, values: &[T]) -> Result<(), CapacityError> {
+        if values.len() > self.gap_end - self.gap_start {
+            return Err(CapacityError);
+        }
+        for value in values {
+            unsafe { ptr::write(self.ptr().add(self.gap_start), value.clone()) };
+            self.gap_start += 1;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for GapBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T, const N: usize> Drop for GapBuffer<T, N> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+/// A [`GapBuffer`] of UTF-8 text whose cursor stays on char boundaries.
+pub struct GapString<const N: usize> {
+    buf: GapBuffer<u8, N>,
+}
+impl<const N: usize> Default for GapString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: usize> GapString<N> {
+    pub const fn new() -> Self {
+        GapString {
+            buf: GapBuffer::new(),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+    /// Length in bytes.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.len()
+    }
+    /// Byte offset of the cursor.
+    pub fn cursor(&self) -> usize {
+        self.buf.cursor()
+    }
+    /// Panics if `cursor` is out of bounds or not on a char boundary.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        let (before, after) = self.as_strs();
+        if cursor < before.len() {
+            assert!(before.is_char_boundary(cursor));
+        } else {
+            assert!(after.is_char_boundary(cursor - before.len()));
+        }
+        self.buf.set_cursor(cursor);
+    }
+    /// Moves the cursor one char to the left.
+    pub fn move_left(&mut self) -> bool {
+        match self.as_strs().0.chars().next_back() {
+            Some(ch) => {
+                self.buf.set_cursor(self.cursor() - ch.len_utf8());
+                true
+            }
+            None => false,
+        }
+    }
+    /// Moves the cursor one char to the right.
+    pub fn move_right(&mut self) -> bool {
+        match self.as_strs().1.chars().next() {
+            Some(ch) => {
+                self.buf.set_cursor(self.cursor() + ch.len_utf8());
+                true
+            }
+            None => false,
+        }
+    }
+    pub fn insert(&mut self, ch: char) -> Result<(), char> {
+        let mut buf = [0; 4];
+        self.insert_str(ch.encode_utf8(&mut buf)).map_err(|_| ch)
+    }
+    /// Inserts all of `string` before the cursor, or nothing.
+    pub fn insert_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        self.buf.insert_slice(string.as_bytes())
+    }
+    pub fn delete_backward(&mut self) -> Option<char> {
+        let ch = self.as_strs().0.chars().next_back()?;
+        for _ in 0..ch.len_utf8() {
+            self.buf.delete_backward();
+        }
+        Some(ch)
+    }
+    pub fn delete_forward(&mut self) -> Option<char> {
+        let ch = self.as_strs().1.chars().next()?;
+        for _ in 0..ch.len_utf8() {
+            self.buf.delete_forward();
+        }
+        Some(ch)
+    }
+    /// The text before and after the cursor.
+    pub fn as_strs(&self) -> (&str, &str) {
+        let (before, after) = self.buf.as_slices();
+        unsafe {
+            (
+                str::from_utf8_unchecked(before),
+                str::from_utf8_unchecked(after),
+            )
+        }
+    }
+    pub fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
+        let (before, after) = self.as_strs();
+        before.chars().chain(after.chars())
+    }
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+}
+
+impl<const N: usize> fmt::Write for GapString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.insert_str(s).map_err(|_| fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.insert(c).map_err(|_| fmt::Error)
+    }
+}
+impl<const N: usize> fmt::Display for GapString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (before, after) = self.as_strs();
+        f.write_str(before)?;
+        f.write_str(after)
+    }
+}
+impl<const N: usize> fmt::Debug for GapString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (before, after) = self.as_strs();
+        f.debug_tuple("GapString")
+            .field(&before)
+            .field(&after)
+            .finish()
+    }
+}
@@ -1,37 +1,77 @@
 //! 历史记录
 
+use crate::storage::Storage;
 use core::borrow::Borrow;
 use core::convert::AsRef;
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use core::ops::{Deref, DerefMut};
 use core::{ptr, slice};
 
-pub struct History<T, const N: usize> {
+pub struct HistoryInner<T, S: Storage<T> + ?Sized> {
     is_full: bool,
     last: usize,
-    logs: MaybeUninit<[T; N]>,
+    _marker: PhantomData<T>,
+    logs: S,
+}
+pub type History<T, const N: usize> = HistoryInner<T, [MaybeUninit<T>; N]>;
+/// A `History` of any capacity, see [`History::as_view`].
+pub type HistoryView<T> = HistoryInner<T, [MaybeUninit<T>]>;
+/// A `History` in a buffer handed over at runtime.
+pub type BorrowedHistory<'a, T> = HistoryInner<T, &'a mut [MaybeUninit<T>]>;
+
+impl<T, const N: usize> Default for History<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 impl<T, const N: usize> History<T, N> {
-    const CAPACITY: usize = N;
+    const INIT: MaybeUninit<T> = MaybeUninit::uninit();
     pub const fn new() -> Self {
-        History {
+        HistoryInner {
             is_full: false,
             last: 0,
-            logs: MaybeUninit::uninit(),
+            _marker: PhantomData,
+            logs: [Self::INIT; N],
         }
     }
+    pub fn as_view(&self) -> &HistoryView<T> {
+        self
+    }
+    pub fn as_mut_view(&mut self) -> &mut HistoryView<T> {
+        self
+    }
+}
+impl<T, S: Storage<T>> HistoryInner<T, S> {
+    /// Creates an empty `History` in `logs`, whatever it holds.
+    pub fn from_storage(logs: S) -> Self {
+        HistoryInner {
+            is_full: false,
+            last: 0,
+            _marker: PhantomData,
+            logs,
+        }
+    }
+}
+impl<T, S: Storage<T> + ?Sized> HistoryInner<T, S> {
     fn as_ptr(&self) -> *mut T {
-        self.logs.as_ptr() as *mut T
+        self.logs.buffer().as_ptr() as *mut T
+    }
+    pub fn capacity(&self) -> usize {
+        self.logs.buffer().len()
     }
     /// 添加记录
     pub fn insert(&mut self, value: T) {
+        if self.capacity() == 0 {
+            return;
+        }
         let last = self.last;
         if self.is_full {
-            unsafe { slice::from_raw_parts_mut(self.as_ptr(), Self::CAPACITY)[last] = value };
+            unsafe { slice::from_raw_parts_mut(self.as_ptr(), self.capacity())[last] = value };
         } else {
             unsafe { ptr::write(self.as_ptr().add(last), value) };
         };
-        if last == Self::CAPACITY - 1 {
+        if last == self.capacity() - 1 {
             self.last = 0;
             self.is_full = true;
         } else {
@@ -39,37 +79,37 @@ impl<T, const N: usize> History<T, N> {
         }
     }
 }
-impl<T, const N: usize> Deref for History<T, N> {
+impl<T, S: Storage<T> + ?Sized> Deref for HistoryInner<T, S> {
     type Target = [T];
 
     fn deref(&self) -> &[T] {
         if self.is_full {
-            unsafe { slice::from_raw_parts(self.as_ptr(), Self::CAPACITY) }
+            unsafe { slice::from_raw_parts(self.as_ptr(), self.capacity()) }
         } else {
             unsafe { slice::from_raw_parts(self.as_ptr(), self.last) }
         }
     }
 }
-impl<T, const N: usize> DerefMut for History<T, N> {
+impl<T, S: Storage<T> + ?Sized> DerefMut for HistoryInner<T, S> {
     fn deref_mut(&mut self) -> &mut [T] {
         if self.is_full {
-            unsafe { slice::from_raw_parts_mut(self.as_ptr(), Self::CAPACITY) }
+            unsafe { slice::from_raw_parts_mut(self.as_ptr(), self.capacity()) }
         } else {
             unsafe { slice::from_raw_parts_mut(self.as_ptr(), self.last) }
         }
     }
 }
-impl<T, const N: usize> AsRef<[T]> for History<T, N> {
+impl<T, S: Storage<T> + ?Sized> AsRef<[T]> for HistoryInner<T, S> {
     fn as_ref(&self) -> &[T] {
         self
     }
 }
-impl<T, const N: usize> Borrow<[T]> for History<T, N> {
+impl<T, S: Storage<T> + ?Sized> Borrow<[T]> for HistoryInner<T, S> {
     fn borrow(&self) -> &[T] {
         &self[..]
     }
 }
-impl<T, const N: usize> Drop for History<T, N> {
+impl<T, S: Storage<T> + ?Sized> Drop for HistoryInner<T, S> {
     fn drop(&mut self) {
         unsafe { ptr::drop_in_place(self.deref_mut()) };
     }
@@ -0,0 +1,195 @@
+//! Indexed priority queue
+//!
+//! Ids in `0..N` are kept in a binary heap ordered by their priority,
+//! least priority first, with a position table for O(1) lookup by id.
+
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+const NONE: usize = usize::MAX;
+
+pub struct IndexedPriorityQueue<P, const N: usize> {
+    /// Ids in heap order.
+    heap: [usize; N],
+    /// Position of each id in `heap`, `NONE` if absent.
+    positions: [usize; N],
+    /// Priority of each id, initialized only for ids in the queue.
+    priorities: MaybeUninit<[P; N]>,
+    len: usize,
+}
+impl<P, const N: usize> Default for IndexedPriorityQueue<P, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<P, const N: usize> IndexedPriorityQueue<P, N> {
+    const CAPACITY: usize = N;
+    pub const fn new() -> Self {
+        IndexedPriorityQueue {
+            heap: [0; N],
+            positions: [NONE; N],
+            priorities: MaybeUninit::uninit(),
+            len: 0,
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    fn ptr(&self) -> *mut P {
+        self.priorities.as_ptr() as *mut P
+    }
+    pub fn contains(&self, id: usize) -> bool {
+        id < Self::CAPACITY && self.positions[id] != NONE
+    }
+    pub fn priority(&self, id: usize) -> Option<&P> {
+        if self.contains(id) {
+            unsafe { Some(&*self.ptr().add(id)) }
+        } else {
+            None
+        }
+    }
+    pub fn peek(&self) -> Option<(usize, &P)> {
+        if self.is_empty() {
+            None
+        } else {
+            let id = self.heap[0];
+            unsafe { Some((id, &*self.ptr().add(id))) }
+        }
+    }
+    /// Ids in heap order.
+    pub fn ids(&self) -> &[usize] {
+        &self.heap[..self.len]
+    }
+    pub fn clear(&mut self) {
+        for i in 0..self.len {
+            let id = self.heap[i];
+            self.positions[id] = NONE;
+            unsafe { ptr::drop_in_place(self.ptr().add(id)) };
+        }
+        self.len = 0;
+    }
+    #[inline]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a]] = a;
+        self.positions[self.heap[b]] = b;
+    }
+}
+impl<P: Ord, const N: usize> IndexedPriorityQueue<P, N> {
+    #[inline]
+    fn less(&self, a: usize, b: usize) -> bool {
+        unsafe { *self.ptr().add(self.heap[a]) < *self.ptr().add(self.heap[b]) }
+    }
+    fn sift_up(&mut self, mut pos: usize) -> usize {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if !self.less(pos, parent) {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+        pos
+    }
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let mut child = 2 * pos + 1;
+            if child >= self.len {
+                break;
+            }
+            if child + 1 < self.len && self.less(child + 1, child) {
+                child += 1;
+            }
+            if !self.less(child, pos) {
+                break;
+            }
+            self.swap(pos, child);
+            pos = child;
+        }
+    }
+    fn restore(&mut self, pos: usize) {
+        if self.sift_up(pos) == pos {
+            self.sift_down(pos);
+        }
+    }
+    /// Fails if `id` is out of range or already queued.
+    pub fn push(&mut self, id: usize, priority: P) -> Result<(), P> {
+        if id >= Self::CAPACITY || self.positions[id] != NONE {
+            return Err(priority);
+        }
+        unsafe { ptr::write(self.ptr().add(id), priority) };
+        let pos = self.len;
+        self.heap[pos] = id;
+        self.positions[id] = pos;
+        self.len += 1;
+        self.sift_up(pos);
+        Ok(())
+    }
+    pub fn pop(&mut self) -> Option<(usize, P)> {
+        if self.is_empty() {
+            return None;
+        }
+        let id = self.heap[0];
+        self.remove(id).map(|priority| (id, priority))
+    }
+    pub fn remove(&mut self, id: usize) -> Option<P> {
+        if !self.contains(id) {
+            return None;
+        }
+        let pos = self.positions[id];
+        let last = self.len - 1;
+        self.swap(pos, last);
+        self.len -= 1;
+        self.positions[id] = NONE;
+        let priority = unsafe { ptr::read(self.ptr().add(id)) };
+        if pos < self.len {
+            self.restore(pos);
+        }
+        Some(priority)
+    }
+    /// Sets the priority of a queued id, returning the old one.
+    pub fn change_priority(&mut self, id: usize, priority: P) -> Option<P> {
+        if !self.contains(id) {
+            return None;
+        }
+        let old = unsafe { ptr::replace(self.ptr().add(id), priority) };
+        self.restore(self.positions[id]);
+        Some(old)
+    }
+    /// Pushes `id`, or lowers its priority if it is queued with a greater one.
+    ///
+    /// Returns whether the queue changed.
+    pub fn push_or_decrease(&mut self, id: usize, priority: P) -> bool {
+        match self.priority(id) {
+            Some(old) if *old <= priority => false,
+            Some(_) => {
+                self.change_priority(id, priority);
+                true
+            }
+            None => self.push(id, priority).is_ok(),
+        }
+    }
+}
+impl<P: fmt::Debug, const N: usize> fmt::Debug for IndexedPriorityQueue<P, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.ids()
+                    .iter()
+                    .map(|id| (id, unsafe { &*self.ptr().add(*id) })),
+            )
+            .finish()
+    }
+}
+impl<P, const N: usize> Drop for IndexedPriorityQueue<P, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
@@ -1,13 +1,58 @@
 #![no_std]
 
+pub mod binary_heap;
+pub mod bit_set;
+pub mod bounded_map;
+mod double_buffer;
+pub mod fixed_hash_map;
+mod fixed_index_map;
+pub mod format;
+mod gap_buffer;
 mod history;
+mod indexed_priority_queue;
 mod linear_map;
-mod linear_set;
+pub mod linear_set;
+pub mod linked_list;
+mod lru_cache;
+mod min_max_heap;
+#[cfg(target_has_atomic = "ptr")]
+pub mod pool;
+mod priority_fifo;
+pub mod range_set;
+mod record_queue;
+pub mod slab;
+mod sparse_set;
+pub mod storage;
+pub mod string;
+pub mod timer_queue;
+pub mod traits;
+mod unique_queue;
 mod vec;
 mod vec_deque;
 
-pub use history::History;
-pub use linear_map::LinearMap;
+pub use binary_heap::BinaryHeap;
+pub use bit_set::BitSet;
+pub use bounded_map::BoundedMap;
+pub use double_buffer::{DoubleBuffer, TripleBuffer};
+pub use fixed_hash_map::FixedHashMap;
+pub use fixed_index_map::FixedIndexMap;
+pub use gap_buffer::{GapBuffer, GapString};
+pub use history::{BorrowedHistory, History, HistoryInner, HistoryView};
+pub use indexed_priority_queue::IndexedPriorityQueue;
+pub use linear_map::{LinearMap, LinearMapInner, LinearMapView};
 pub use linear_set::LinearSet;
-pub use vec::Vec;
-pub use vec_deque::VecDeque;
+pub use linked_list::LinkedList;
+pub use lru_cache::LruCache;
+pub use min_max_heap::MinMaxHeap;
+#[cfg(target_has_atomic = "ptr")]
+pub use pool::Pool;
+pub use priority_fifo::PriorityFifo;
+pub use range_set::{RangeMap, RangeSet};
+pub use record_queue::RecordQueue;
+pub use slab::Slab;
+pub use sparse_set::SparseSet;
+pub use string::String;
+pub use timer_queue::TimerQueue;
+pub use unique_queue::{HashedUniqueQueue, UniqueQueue};
+pub use vec::{BorrowedVec, Vec, VecInner, VecView};
+pub use vec_deque::{BorrowedVecDeque, VecDeque, VecDequeInner, VecDequeView};
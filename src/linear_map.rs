@@ -1,25 +1,53 @@
 //! Map
 
-use crate::vec::Vec;
+use crate::storage::Storage;
+use crate::vec::{Vec, VecInner};
 use core::borrow::{Borrow, BorrowMut};
 use core::convert::{AsMut, AsRef};
-use core::mem;
+use core::mem::{self, MaybeUninit};
 use core::ops;
 
-pub struct LinearMap<K, V, const N: usize> {
-    vec: Vec<(K, V), N>,
+pub struct LinearMapInner<K, V, S: Storage<(K, V)> + ?Sized> {
+    vec: VecInner<(K, V), S>,
+}
+pub type LinearMap<K, V, const N: usize> = LinearMapInner<K, V, [MaybeUninit<(K, V)>; N]>;
+/// A `LinearMap` of any capacity, see [`LinearMap::as_view`].
+pub type LinearMapView<K, V> = LinearMapInner<K, V, [MaybeUninit<(K, V)>]>;
+
+impl<K, V, const N: usize> Default for LinearMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 impl<K, V, const N: usize> LinearMap<K, V, N> {
-    const CAPACITY: usize = N;
     pub const fn new() -> Self {
-        LinearMap { vec: Vec::new() }
+        LinearMapInner { vec: Vec::new() }
     }
+    pub fn as_view(&self) -> &LinearMapView<K, V> {
+        self
+    }
+    pub fn as_mut_view(&mut self) -> &mut LinearMapView<K, V> {
+        self
+    }
+}
+impl<K, V, S: Storage<(K, V)> + ?Sized> LinearMapInner<K, V, S> {
     pub fn capacity(&self) -> usize {
-        Self::CAPACITY
+        self.vec.capacity()
+    }
+    pub(crate) fn clear(&mut self) {
+        self.vec.clear();
+    }
+    /// Removes the last entry.
+    pub(crate) fn pop(&mut self) -> Option<(K, V)> {
+        self.vec.pop()
+    }
+    /// Removes the entry at `index`, replacing it with the last one.
+    pub fn swap_remove(&mut self, index: usize) -> (K, V) {
+        self.vec.swap_remove(index)
     }
 }
-impl<K: PartialEq, V, const N: usize> LinearMap<K, V, N> {
-    fn get_index(&self, key: &K) -> Option<usize> {
+impl<K: PartialEq, V, S: Storage<(K, V)> + ?Sized> LinearMapInner<K, V, S> {
+    pub(crate) fn get_index(&self, key: &K) -> Option<usize> {
         if let Some((i, _item)) = self.iter().enumerate().find(|(_i, item)| &item.0 == key) {
             Some(i)
         } else {
@@ -53,7 +81,7 @@ impl<K: PartialEq, V, const N: usize> LinearMap<K, V, N> {
     }
     pub fn remove(&mut self, key: &K) -> Option<V> {
         if let Some(i) = self.get_index(key) {
-            let rm = self.vec.swap_remove(i);
+            let rm = self.swap_remove(i);
             Some(rm.1)
         } else {
             None
@@ -61,34 +89,34 @@ impl<K: PartialEq, V, const N: usize> LinearMap<K, V, N> {
     }
 }
 
-impl<K, V, const N: usize> ops::Deref for LinearMap<K, V, N> {
+impl<K, V, S: Storage<(K, V)> + ?Sized> ops::Deref for LinearMapInner<K, V, S> {
     type Target = [(K, V)];
 
     fn deref(&self) -> &[(K, V)] {
         self.vec.deref()
     }
 }
-impl<K, V, const N: usize> ops::DerefMut for LinearMap<K, V, N> {
+impl<K, V, S: Storage<(K, V)> + ?Sized> ops::DerefMut for LinearMapInner<K, V, S> {
     fn deref_mut(&mut self) -> &mut [(K, V)] {
         self.vec.deref_mut()
     }
 }
-impl<K, V, const N: usize> AsRef<[(K, V)]> for LinearMap<K, V, N> {
+impl<K, V, S: Storage<(K, V)> + ?Sized> AsRef<[(K, V)]> for LinearMapInner<K, V, S> {
     fn as_ref(&self) -> &[(K, V)] {
         self
     }
 }
-impl<K, V, const N: usize> AsMut<[(K, V)]> for LinearMap<K, V, N> {
+impl<K, V, S: Storage<(K, V)> + ?Sized> AsMut<[(K, V)]> for LinearMapInner<K, V, S> {
     fn as_mut(&mut self) -> &mut [(K, V)] {
         self
     }
 }
-impl<K, V, const N: usize> Borrow<[(K, V)]> for LinearMap<K, V, N> {
+impl<K, V, S: Storage<(K, V)> + ?Sized> Borrow<[(K, V)]> for LinearMapInner<K, V, S> {
     fn borrow(&self) -> &[(K, V)] {
         &self[..]
     }
 }
-impl<K, V, const N: usize> BorrowMut<[(K, V)]> for LinearMap<K, V, N> {
+impl<K, V, S: Storage<(K, V)> + ?Sized> BorrowMut<[(K, V)]> for LinearMapInner<K, V, S> {
     fn borrow_mut(&mut self) -> &mut [(K, V)] {
         &mut self[..]
     }
@@ -3,11 +3,19 @@
 use crate::vec::Vec;
 use core::borrow::{Borrow, BorrowMut};
 use core::convert::{AsMut, AsRef};
+use core::iter::Chain;
+use core::mem;
 use core::ops;
+use core::slice;
 
 pub struct LinearSet<T, const N: usize> {
     vec: Vec<T, N>,
 }
+impl<T, const N: usize> Default for LinearSet<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 impl<T, const N: usize> LinearSet<T, N> {
     const CAPACITY: usize = N;
     pub const fn new() -> Self {
@@ -36,7 +44,7 @@ impl<T: PartialEq, const N: usize> LinearSet<T, N> {
         self.iter().any(|x| x == value)
     }
     pub fn insert(&mut self, value: T) -> Result<bool, T> {
-        if let Some(_) = self.get_index(&value) {
+        if self.get_index(&value).is_some() {
             Ok(false)
         } else {
             self.vec.push(value)?;
@@ -44,13 +52,135 @@ impl<T: PartialEq, const N: usize> LinearSet<T, N> {
         }
     }
     pub fn remove(&mut self, value: &T) -> bool {
+        self.take(value).is_some()
+    }
+    pub fn take(&mut self, value: &T) -> Option<T> {
         if let Some(i) = self.get_index(value) {
-            self.vec.swap_remove(i);
-            true
+            Some(self.vec.swap_remove(i))
         } else {
-            false
+            None
+        }
+    }
+    /// Adds a value, replacing the existing equal one if any.
+    pub fn replace(&mut self, value: T) -> Result<Option<T>, T> {
+        if let Some(i) = self.get_index(&value) {
+            Ok(Some(mem::replace(&mut self.vec[i], value)))
+        } else {
+            self.vec.push(value)?;
+            Ok(None)
+        }
+    }
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut i = 0;
+        while i < self.len() {
+            if f(&self[i]) {
+                i += 1;
+            } else {
+                self.vec.swap_remove(i);
+            }
+        }
+    }
+    /// Inserts every value of `iter`, stopping at the first one that does not fit.
+    pub fn extend_from<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), T> {
+        for value in iter {
+            self.insert(value)?;
+        }
+        Ok(())
+    }
+    pub fn union<'a, const M: usize>(&'a self, other: &'a LinearSet<T, M>) -> Union<'a, T, N> {
+        Union {
+            iter: self.iter().chain(other.difference(self)),
         }
     }
+    pub fn intersection<'a, const M: usize>(
+        &'a self,
+        other: &'a LinearSet<T, M>,
+    ) -> Intersection<'a, T, M> {
+        Intersection {
+            iter: self.iter(),
+            other,
+        }
+    }
+    pub fn difference<'a, const M: usize>(
+        &'a self,
+        other: &'a LinearSet<T, M>,
+    ) -> Difference<'a, T, M> {
+        Difference {
+            iter: self.iter(),
+            other,
+        }
+    }
+    pub fn symmetric_difference<'a, const M: usize>(
+        &'a self,
+        other: &'a LinearSet<T, M>,
+    ) -> SymmetricDifference<'a, T, N, M> {
+        SymmetricDifference {
+            iter: self.difference(other).chain(other.difference(self)),
+        }
+    }
+    pub fn is_disjoint<const M: usize>(&self, other: &LinearSet<T, M>) -> bool {
+        self.iter().all(|x| !other.contains(x))
+    }
+    pub fn is_subset<const M: usize>(&self, other: &LinearSet<T, M>) -> bool {
+        self.len() <= other.len() && self.iter().all(|x| other.contains(x))
+    }
+    pub fn is_superset<const M: usize>(&self, other: &LinearSet<T, M>) -> bool {
+        other.is_subset(self)
+    }
+}
+
+/// Values in `self` or `other`, without duplicates.
+pub struct Union<'a, T, const N: usize> {
+    iter: Chain<slice::Iter<'a, T>, Difference<'a, T, N>>,
+}
+impl<'a, T: PartialEq, const N: usize> Iterator for Union<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+}
+
+/// Values in both `self` and `other`.
+pub struct Intersection<'a, T, const M: usize> {
+    iter: slice::Iter<'a, T>,
+    other: &'a LinearSet<T, M>,
+}
+impl<'a, T: PartialEq, const M: usize> Iterator for Intersection<'a, T, M> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let other = self.other;
+        self.iter.find(|x| other.contains(x))
+    }
+}
+
+/// Values in `self` but not in `other`.
+pub struct Difference<'a, T, const M: usize> {
+    iter: slice::Iter<'a, T>,
+    other: &'a LinearSet<T, M>,
+}
+impl<'a, T: PartialEq, const M: usize> Iterator for Difference<'a, T, M> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let other = self.other;
+        self.iter.find(|x| !other.contains(x))
+    }
+}
+
+/// Values in `self` or `other`, but not in both.
+pub struct SymmetricDifference<'a, T, const N: usize, const M: usize> {
+    iter: Chain<Difference<'a, T, M>, Difference<'a, T, N>>,
+}
+impl<'a, T: PartialEq, const N: usize, const M: usize> Iterator
+    for SymmetricDifference<'a, T, N, M>
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
 }
 
 impl<T, const N: usize> ops::Deref for LinearSet<T, N> {
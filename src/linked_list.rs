@@ -0,0 +1,427 @@
+//! Linked list
+//!
+//! Nodes live in an array and are linked by index, so the list can be moved
+//! freely and a node can be unlinked in O(1) given its handle.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+const NIL: usize = usize::MAX;
+/// `prev` of a slot that holds no element.
+const FREE: usize = usize::MAX - 1;
+
+/// Refers to a node for as long as its element stays in the list.
+///
+/// The slot's generation is bumped on removal, so a handle to a removed
+/// element is rejected even after its slot has been reused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Handle {
+    index: usize,
+    generation: u32,
+}
+
+pub struct LinkedList<T, const N: usize> {
+    buf: MaybeUninit<[T; N]>,
+    next: [usize; N],
+    prev: [usize; N],
+    generations: [u32; N],
+    head: usize,
+    tail: usize,
+    /// Free slots, linked through `next`.
+    free: usize,
+    /// Slots from here on have never been used.
+    unused: usize,
+    len: usize,
+}
+impl<T, const N: usize> Default for LinkedList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> LinkedList<T, N> {
+    const CAPACITY: usize = N;
+    pub const fn new() -> Self {
+        LinkedList {
+            buf: MaybeUninit::uninit(),
+            next: [NIL; N],
+            prev: [FREE; N],
+            generations: [0; N],
+            head: NIL,
+            tail: NIL,
+            free: NIL,
+            unused: 0,
+            len: 0,
+        }
+    }
+    fn ptr(&self) -> *mut T {
+        self.buf.as_ptr() as *mut T
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn is_full(&self) -> bool {
+        self.len == Self::CAPACITY
+    }
+    fn handle(&self, index: usize) -> Handle {
+        Handle {
+            index,
+            generation: self.generations[index],
+        }
+    }
+    /// Slot of `handle` if its element is still in the list.
+    fn index_of(&self, handle: Handle) -> Option<usize> {
+        let index = handle.index;
+        if index < self.unused
+            && self.prev[index] != FREE
+            && self.generations[index] == handle.generation
+        {
+            Some(index)
+        } else {
+            None
+        }
+    }
+    fn slot_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index == NIL {
+            None
+        } else {
+            unsafe { Some(&mut *self.ptr().add(index)) }
+        }
+    }
+    fn alloc(&mut self, value: T) -> Result<usize, T> {
+        let index = if self.free != NIL {
+            let index = self.free;
+            self.free = self.next[index];
+            index
+        } else if self.unused < Self::CAPACITY {
+            self.unused += 1;
+            self.unused - 1
+        } else {
+            return Err(value);
+        };
+        unsafe { ptr::write(self.ptr().add(index), value) };
+        self.len += 1;
+        Ok(index)
+    }
+    /// Links slot `index` between `prev` and `next`, either may be `NIL`.
+    fn link(&mut self, index: usize, prev: usize, next: usize) {
+        self.prev[index] = prev;
+        self.next[index] = next;
+        if prev == NIL {
+            self.head = index;
+        } else {
+            self.next[prev] = index;
+        }
+        if next == NIL {
+            self.tail = index;
+        } else {
+            self.prev[next] = index;
+        }
+    }
+    fn unlink(&mut self, index: usize) -> T {
+        let (prev, next) = (self.prev[index], self.next[index]);
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.next[prev] = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.prev[next] = prev;
+        }
+        self.prev[index] = FREE;
+        self.generations[index] = self.generations[index].wrapping_add(1);
+        self.next[index] = self.free;
+        self.free = index;
+        self.len -= 1;
+        unsafe { ptr::read(self.ptr().add(index)) }
+    }
+    pub fn push_front(&mut self, value: T) -> Result<Handle, T> {
+        let index = self.alloc(value)?;
+        self.link(index, NIL, self.head);
+        Ok(self.handle(index))
+    }
+    pub fn push_back(&mut self, value: T) -> Result<Handle, T> {
+        let index = self.alloc(value)?;
+        self.link(index, self.tail, NIL);
+        Ok(self.handle(index))
+    }
+    /// Fails if the list is full or `handle` is stale.
+    pub fn insert_after(&mut self, handle: Handle, value: T) -> Result<Handle, T> {
+        let at = match self.index_of(handle) {
+            Some(at) => at,
+            None => return Err(value),
+        };
+        let index = self.alloc(value)?;
+        self.link(index, at, self.next[at]);
+        Ok(self.handle(index))
+    }
+    /// Fails if the list is full or `handle` is stale.
+    pub fn insert_before(&mut self, handle: Handle, value: T) -> Result<Handle, T> {
+        let at = match self.index_of(handle) {
+            Some(at) => at,
+            None => return Err(value),
+        };
+        let index = self.alloc(value)?;
+        self.link(index, self.prev[at], at);
+        Ok(self.handle(index))
+    }
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.head == NIL {
+            None
+        } else {
+            Some(self.unlink(self.head))
+        }
+    }
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.tail == NIL {
+            None
+        } else {
+            Some(self.unlink(self.tail))
+        }
+    }
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        let index = self.index_of(handle)?;
+        Some(self.unlink(index))
+    }
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        let index = self.index_of(handle)?;
+        unsafe { Some(&*self.ptr().add(index)) }
+    }
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> {
+        let index = self.index_of(handle)?;
+        self.slot_mut(index)
+    }
+    pub fn front_handle(&self) -> Option<Handle> {
+        Some(self.head)
+            .filter(|i| *i != NIL)
+            .map(|i| self.handle(i))
+    }
+    pub fn back_handle(&self) -> Option<Handle> {
+        Some(self.tail)
+            .filter(|i| *i != NIL)
+            .map(|i| self.handle(i))
+    }
+    pub fn next_handle(&self, handle: Handle) -> Option<Handle> {
+        self.index_of(handle)
+            .map(|i| self.next[i])
+            .filter(|i| *i != NIL)
+            .map(|i| self.handle(i))
+    }
+    pub fn prev_handle(&self, handle: Handle) -> Option<Handle> {
+        self.index_of(handle)
+            .map(|i| self.prev[i])
+            .filter(|i| *i != NIL)
+            .map(|i| self.handle(i))
+    }
+    pub fn front(&self) -> Option<&T> {
+        self.front_handle().and_then(|h| self.get(h))
+    }
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.front_handle().and_then(move |h| self.get_mut(h))
+    }
+    pub fn back(&self) -> Option<&T> {
+        self.back_handle().and_then(|h| self.get(h))
+    }
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.back_handle().and_then(move |h| self.get_mut(h))
+    }
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            list: self,
+            index: self.head,
+        }
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let ptr = self.ptr();
+        let next = &self.next;
+        let mut index = self.head;
+        core::iter::from_fn(move || {
+            if index == NIL {
+                None
+            } else {
+                let item = unsafe { &mut *ptr.add(index) };
+                index = next[index];
+                Some(item)
+            }
+        })
+    }
+    /// Cursor at the front, or at the "ghost" position if the list is empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, N> {
+        CursorMut {
+            current: self.head,
+            list: self,
+        }
+    }
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, N> {
+        CursorMut {
+            current: self.tail,
+            list: self,
+        }
+    }
+    /// Cursor at `handle`, or at the "ghost" position if it is stale.
+    pub fn cursor_mut(&mut self, handle: Handle) -> CursorMut<'_, T, N> {
+        let current = self.index_of(handle).unwrap_or(NIL);
+        CursorMut {
+            list: self,
+            current,
+        }
+    }
+    /// Stable sort by relinking nodes, handles stay valid.
+    ///
+    /// The new order is worked out in a scratch array and only linked in
+    /// once it is complete, so a panicking `compare` leaves the list as it was.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        // (slot, position before sorting), the position breaks ties.
+        let mut order = [(NIL, 0); N];
+        let mut index = self.head;
+        for (position, entry) in order.iter_mut().enumerate().take(self.len) {
+            *entry = (index, position);
+            index = self.next[index];
+        }
+        let order = &mut order[..self.len];
+        let ptr = self.ptr();
+        order.sort_unstable_by(|a, b| {
+            let (x, y) = unsafe { (&*ptr.add(a.0), &*ptr.add(b.0)) };
+            compare(x, y).then(a.1.cmp(&b.1))
+        });
+        let mut prev = NIL;
+        for &(index, _position) in order.iter() {
+            self.prev[index] = prev;
+            if prev == NIL {
+                self.head = index;
+            } else {
+                self.next[prev] = index;
+            }
+            prev = index;
+        }
+        if prev != NIL {
+            self.next[prev] = NIL;
+        }
+        self.tail = prev;
+    }
+}
+impl<T: Ord, const N: usize> LinkedList<T, N> {
+    pub fn sort(&mut self) {
+        self.sort_by(T::cmp);
+    }
+}
+
+pub struct Iter<'a, T, const N: usize> {
+    list: &'a LinkedList<T, N>,
+    index: usize,
+}
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.index == NIL {
+            None
+        } else {
+            let item = unsafe { &*self.list.ptr().add(self.index) };
+            self.index = self.list.next[self.index];
+            Some(item)
+        }
+    }
+}
+impl<'a, T, const N: usize> IntoIterator for &'a LinkedList<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Iter<'a, T, N> {
+        self.iter()
+    }
+}
+
+/// Cursor that can move and edit the list.
+///
+/// Like `std`'s cursors, it wraps through a "ghost" position between the back and the front.
+pub struct CursorMut<'a, T, const N: usize> {
+    list: &'a mut LinkedList<T, N>,
+    current: usize,
+}
+impl<T, const N: usize> CursorMut<'_, T, N> {
+    pub fn handle(&self) -> Option<Handle> {
+        Some(self.current)
+            .filter(|i| *i != NIL)
+            .map(|i| self.list.handle(i))
+    }
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.list.slot_mut(self.current)
+    }
+    pub fn move_next(&mut self) {
+        self.current = if self.current == NIL {
+            self.list.head
+        } else {
+            self.list.next[self.current]
+        };
+    }
+    pub fn move_prev(&mut self) {
+        self.current = if self.current == NIL {
+            self.list.tail
+        } else {
+            self.list.prev[self.current]
+        };
+    }
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = if self.current == NIL {
+            self.list.head
+        } else {
+            self.list.next[self.current]
+        };
+        self.list.slot_mut(next)
+    }
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = if self.current == NIL {
+            self.list.tail
+        } else {
+            self.list.prev[self.current]
+        };
+        self.list.slot_mut(prev)
+    }
+    /// At the ghost position, inserts at the front.
+    pub fn insert_after(&mut self, value: T) -> Result<Handle, T> {
+        match self.handle() {
+            Some(handle) => self.list.insert_after(handle, value),
+            None => self.list.push_front(value),
+        }
+    }
+    /// At the ghost position, inserts at the back.
+    pub fn insert_before(&mut self, value: T) -> Result<Handle, T> {
+        match self.handle() {
+            Some(handle) => self.list.insert_before(handle, value),
+            None => self.list.push_back(value),
+        }
+    }
+    /// Removes the current element and moves to the next one.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.current == NIL {
+            return None;
+        }
+        let index = self.current;
+        self.current = self.list.next[index];
+        Some(self.list.unlink(index))
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for LinkedList<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T, const N: usize> Drop for LinkedList<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
@@ -0,0 +1,114 @@
+//! LRU Cache
+
+use crate::bounded_map::Insertion;
+use crate::linear_map::LinearMap;
+use core::fmt;
+use core::mem;
+
+/// Entries are kept from most to least recently used.
+pub struct LruCache<K, V, const N: usize> {
+    map: LinearMap<K, V, N>,
+}
+impl<K, V, const N: usize> Default for LruCache<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K, V, const N: usize> LruCache<K, V, N> {
+    pub const fn new() -> Self {
+        LruCache {
+            map: LinearMap::new(),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+    pub fn is_full(&self) -> bool {
+        self.map.len() == self.map.capacity()
+    }
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+    /// Removes the least recently used entry.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        self.map.pop()
+    }
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.map.last().map(|(k, v)| (k, v))
+    }
+    pub fn peek_mru(&self) -> Option<(&K, &V)> {
+        self.map.first().map(|(k, v)| (k, v))
+    }
+    /// Iterates from most to least recently used.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> {
+        self.map.iter().map(|(k, v)| (k, v))
+    }
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (&K, &mut V)> {
+        self.map.iter_mut().map(|(k, v)| (&*k, v))
+    }
+    fn promote(&mut self, index: usize) {
+        self.map[..=index].rotate_right(1);
+    }
+}
+impl<K: PartialEq, V, const N: usize> LruCache<K, V, N> {
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+    /// Gets a value without marking it as used.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key)
+    }
+    pub fn peek_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.map.get_mut(key)
+    }
+    /// Gets a value and marks it as most recently used.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let i = self.map.get_index(key)?;
+        self.promote(i);
+        Some(&self.map[0].1)
+    }
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let i = self.map.get_index(key)?;
+        self.promote(i);
+        Some(&mut self.map[0].1)
+    }
+    /// Inserts as most recently used, evicting the least recently used entry
+    /// if the cache is full.
+    ///
+    /// With a capacity of `0` nothing is stored and the entry is rejected.
+    pub fn put(&mut self, key: K, value: V) -> Insertion<K, V> {
+        if let Some(i) = self.map.get_index(&key) {
+            self.promote(i);
+            return Insertion::Replaced(mem::replace(&mut self.map[0].1, value));
+        }
+        if self.capacity() == 0 {
+            return Insertion::Rejected(key, value);
+        }
+        let evicted = if self.is_full() { self.map.pop() } else { None };
+        if let Err((key, value)) = self.map.insert(key, value) {
+            return Insertion::Rejected(key, value);
+        }
+        let last = self.map.len() - 1;
+        self.promote(last);
+        match evicted {
+            Some((key, value)) => Insertion::Evicted(key, value),
+            None => Insertion::Inserted,
+        }
+    }
+    pub fn pop(&mut self, key: &K) -> Option<V> {
+        let i = self.map.get_index(key)?;
+        self.map[i..].rotate_left(1);
+        self.map.pop().map(|(_k, v)| v)
+    }
+}
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for LruCache<K, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
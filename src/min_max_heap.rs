@@ -0,0 +1,184 @@
+//! Double-ended priority queue
+//!
+//! Nodes on even levels are less than or equal to their descendants,
+//! nodes on odd levels are greater than or equal to them.
+
+use crate::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter;
+use core::mem;
+
+pub struct MinMaxHeap<T, const N: usize> {
+    data: Vec<T, N>,
+}
+impl<T, const N: usize> Default for MinMaxHeap<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> MinMaxHeap<T, N> {
+    pub const fn new() -> Self {
+        MinMaxHeap { data: Vec::new() }
+    }
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+    /// Elements in arbitrary order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+    pub fn into_vec(self) -> Vec<T, N> {
+        self.data
+    }
+    pub fn peek_min(&self) -> Option<&T> {
+        self.data.first()
+    }
+}
+impl<T: Ord, const N: usize> MinMaxHeap<T, N> {
+    #[inline]
+    fn is_min_level(index: usize) -> bool {
+        (usize::BITS - (index + 1).leading_zeros()) % 2 == 1
+    }
+    fn max_index(&self) -> Option<usize> {
+        match self.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ => Some(if self.data[1] >= self.data[2] { 1 } else { 2 }),
+        }
+    }
+    pub fn peek_max(&self) -> Option<&T> {
+        self.max_index().map(|i| &self.data[i])
+    }
+    fn push_up(&mut self, index: usize) {
+        if index == 0 {
+            return;
+        }
+        let parent = (index - 1) / 2;
+        let ord = if Self::is_min_level(index) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        if self.data[index].cmp(&self.data[parent]) == ord.reverse() {
+            self.data.swap(index, parent);
+            self.push_up_by(parent, ord.reverse());
+        } else {
+            self.push_up_by(index, ord);
+        }
+    }
+    fn push_up_by(&mut self, mut index: usize, ord: Ordering) {
+        while index > 2 {
+            let grandparent = ((index - 1) / 2 - 1) / 2;
+            if self.data[index].cmp(&self.data[grandparent]) != ord {
+                break;
+            }
+            self.data.swap(index, grandparent);
+            index = grandparent;
+        }
+    }
+    fn push_down(&mut self, index: usize) {
+        if Self::is_min_level(index) {
+            self.push_down_by(index, Ordering::Less);
+        } else {
+            self.push_down_by(index, Ordering::Greater);
+        }
+    }
+    fn push_down_by(&mut self, mut index: usize, ord: Ordering) {
+        let len = self.len();
+        loop {
+            let first_child = 2 * index + 1;
+            if first_child >= len {
+                break;
+            }
+            let first_grandchild = 4 * index + 3;
+            let mut m = first_child;
+            for c in iter::once(first_child + 1).chain(first_grandchild..first_grandchild + 4) {
+                if c < len && self.data[c].cmp(&self.data[m]) == ord {
+                    m = c;
+                }
+            }
+            if self.data[m].cmp(&self.data[index]) != ord {
+                break;
+            }
+            self.data.swap(m, index);
+            if m < first_grandchild {
+                break;
+            }
+            let parent = (m - 1) / 2;
+            if self.data[m].cmp(&self.data[parent]) == ord.reverse() {
+                self.data.swap(m, parent);
+            }
+            index = m;
+        }
+    }
+    fn remove_index(&mut self, index: usize) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+        self.data.swap(index, last);
+        let item = self.data.pop();
+        if index < self.len() {
+            self.push_down(index);
+        }
+        item
+    }
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        self.data.push(item)?;
+        self.push_up(self.len() - 1);
+        Ok(())
+    }
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.remove_index(0)
+    }
+    pub fn pop_max(&mut self) -> Option<T> {
+        let index = self.max_index()?;
+        self.remove_index(index)
+    }
+    /// Pushes `item` and then pops the greatest element, in one pass.
+    pub fn push_pop_max(&mut self, mut item: T) -> T {
+        let index = match self.max_index() {
+            Some(index) if self.data[index] > item => index,
+            _ => return item,
+        };
+        mem::swap(&mut item, &mut self.data[index]);
+        if index > 0 && self.data[index] < self.data[0] {
+            self.data.swap(index, 0);
+        }
+        self.push_down(index);
+        item
+    }
+    /// Pushes `item`, or when full, replaces the least element if `item` is greater.
+    ///
+    /// Returns the element that did not stay in the heap, if any.
+    pub fn push_or_replace_min(&mut self, mut item: T) -> Option<T> {
+        if !self.is_full() {
+            return self.push(item).err();
+        }
+        match self.data.first_mut() {
+            Some(min) if *min < item => mem::swap(min, &mut item),
+            _ => return Some(item),
+        }
+        self.push_down(0);
+        Some(item)
+    }
+}
+impl<T: fmt::Debug, const N: usize> fmt::Debug for MinMaxHeap<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
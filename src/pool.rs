@@ -0,0 +1,165 @@
+//! Object pool
+//!
+//! Free slots form a lock-free stack, so a `Pool` can be shared from a `static`.
+//!
+//! Only available on targets with pointer-sized compare-and-swap
+//! (`target_has_atomic = "ptr"`), e.g. not on `thumbv6m-none-eabi`.
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+pub struct Pool<T, const N: usize> {
+    buf: UnsafeCell<MaybeUninit<[T; N]>>,
+    /// Next free slot of each free slot, `N` ends the list.
+    next: [AtomicUsize; N],
+    /// Tag in the high bits, first free slot in the low bits.
+    ///
+    /// Every successful update bumps the tag, so a CAS that read `head`
+    /// before another thread popped and pushed back the same slot fails
+    /// instead of installing a stale `next` (the ABA problem).
+    head: AtomicUsize,
+    /// Slots handed out, only a snapshot while other threads use the pool.
+    len: AtomicUsize,
+}
+// SAFETY: a slot is only accessed through the single `PoolBox` that popped it
+// from the free list, and the list itself is only changed through atomics, so
+// sharing the pool hands each `T` to exactly one thread at a time.
+unsafe impl<T: Send, const N: usize> Sync for Pool<T, N> {}
+impl<T, const N: usize> Default for Pool<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> Pool<T, N> {
+    const CAPACITY: usize = N;
+    const INDEX_BITS: u32 = usize::BITS - N.leading_zeros();
+    const INDEX_MASK: usize = (1 << Self::INDEX_BITS) - 1;
+    const CHECK_TAG: () = assert!(
+        usize::BITS - Self::INDEX_BITS >= 16,
+        "Pool capacity leaves too few bits for the ABA tag"
+    );
+    pub const fn new() -> Self {
+        let () = Self::CHECK_TAG;
+        #[allow(clippy::declare_interior_mutable_const)]
+        const END: AtomicUsize = AtomicUsize::new(0);
+        let mut next = [END; N];
+        let mut i = 0;
+        while i < N {
+            next[i] = AtomicUsize::new(i + 1);
+            i += 1;
+        }
+        Pool {
+            buf: UnsafeCell::new(MaybeUninit::uninit()),
+            next,
+            head: AtomicUsize::new(0),
+            len: AtomicUsize::new(0),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    /// Number of live `PoolBox`es, may be stale as soon as it returns.
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn ptr(&self) -> *mut T {
+        self.buf.get() as *mut T
+    }
+    /// Bumps the tag of `head` and points it at `index`.
+    fn retag(head: usize, index: usize) -> usize {
+        (head.wrapping_add(1 << Self::INDEX_BITS) & !Self::INDEX_MASK) | index
+    }
+    /// Moves `value` into a free slot, failing if the pool is exhausted.
+    pub fn alloc(&self, value: T) -> Result<PoolBox<'_, T, N>, T> {
+        let mut head = self.head.load(Ordering::Acquire);
+        let index = loop {
+            let index = head & Self::INDEX_MASK;
+            if index >= Self::CAPACITY {
+                return Err(value);
+            }
+            let next = self.next[index].load(Ordering::Relaxed);
+            let new = Self::retag(head, next);
+            match self
+                .head
+                .compare_exchange_weak(head, new, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => break index,
+                Err(actual) => head = actual,
+            }
+        };
+        self.len.fetch_add(1, Ordering::Relaxed);
+        unsafe { ptr::write(self.ptr().add(index), value) };
+        Ok(PoolBox {
+            pool: self,
+            index,
+            _marker: PhantomData,
+        })
+    }
+    fn free(&self, index: usize) {
+        self.len.fetch_sub(1, Ordering::Relaxed);
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            self.next[index].store(head & Self::INDEX_MASK, Ordering::Relaxed);
+            let new = Self::retag(head, index);
+            match self
+                .head
+                .compare_exchange_weak(head, new, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => return,
+                Err(actual) => head = actual,
+            }
+        }
+    }
+}
+impl<T, const N: usize> fmt::Debug for Pool<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool").field("capacity", &N).finish()
+    }
+}
+
+/// Owns a value in a `Pool` slot and gives the slot back on drop.
+pub struct PoolBox<'a, T, const N: usize> {
+    pool: &'a Pool<T, N>,
+    index: usize,
+    _marker: PhantomData<T>,
+}
+impl<T, const N: usize> PoolBox<'_, T, N> {
+    /// Moves the value out and frees the slot.
+    pub fn into_inner(this: Self) -> T {
+        let this = ManuallyDrop::new(this);
+        let value = unsafe { ptr::read(this.pool.ptr().add(this.index)) };
+        this.pool.free(this.index);
+        value
+    }
+}
+impl<T, const N: usize> Deref for PoolBox<'_, T, N> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.pool.ptr().add(self.index) }
+    }
+}
+impl<T, const N: usize> DerefMut for PoolBox<'_, T, N> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.pool.ptr().add(self.index) }
+    }
+}
+impl<T: fmt::Debug, const N: usize> fmt::Debug for PoolBox<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+impl<T, const N: usize> Drop for PoolBox<'_, T, N> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.pool.ptr().add(self.index)) };
+        self.pool.free(self.index);
+    }
+}
@@ -0,0 +1,108 @@
+//! Multi-level priority queue
+//!
+//! One FIFO per priority level plus a bitmask of non-empty levels,
+//! so the highest non-empty level is found in O(1).
+
+use crate::vec_deque::VecDeque;
+use core::fmt;
+
+/// `P` levels of up to `N` elements each, level `P - 1` is the highest.
+///
+/// `P` must be at most 32.
+pub struct PriorityFifo<T, const P: usize, const N: usize> {
+    levels: [VecDeque<T, N>; P],
+    mask: u32,
+    len: usize,
+    /// Limit on the total number of elements across levels.
+    budget: usize,
+}
+impl<T, const P: usize, const N: usize> Default for PriorityFifo<T, P, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const P: usize, const N: usize> PriorityFifo<T, P, N> {
+    const INIT: VecDeque<T, N> = VecDeque::new();
+    const CHECK_LEVELS: () = assert!(P <= 32, "PriorityFifo supports at most 32 levels");
+    /// Every level can hold `N` elements.
+    pub const fn new() -> Self {
+        Self::with_budget(P * N)
+    }
+    /// Every level can hold `N` elements, but at most `budget` in total.
+    pub const fn with_budget(budget: usize) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CHECK_LEVELS;
+        PriorityFifo {
+            levels: [Self::INIT; P],
+            mask: 0,
+            len: 0,
+            budget,
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.budget.min(P * N)
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn is_full(&self) -> bool {
+        self.len >= self.capacity()
+    }
+    pub fn levels(&self) -> usize {
+        P
+    }
+    pub fn level(&self, priority: usize) -> Option<&VecDeque<T, N>> {
+        self.levels.get(priority)
+    }
+    pub fn clear(&mut self) {
+        for level in self.levels.iter_mut() {
+            level.clear();
+        }
+        self.mask = 0;
+        self.len = 0;
+    }
+    /// Fails if `priority >= P`, the level is full or the budget is used up.
+    pub fn push(&mut self, priority: usize, value: T) -> Result<(), T> {
+        if priority >= P || self.is_full() {
+            return Err(value);
+        }
+        self.levels[priority].push_back(value)?;
+        self.mask |= 1 << priority;
+        self.len += 1;
+        Ok(())
+    }
+    /// Highest non-empty level.
+    pub fn top_priority(&self) -> Option<usize> {
+        if self.mask == 0 {
+            None
+        } else {
+            Some(31 - self.mask.leading_zeros() as usize)
+        }
+    }
+    pub fn peek(&self) -> Option<&T> {
+        let priority = self.top_priority()?;
+        self.levels[priority].get(0)
+    }
+    /// Removes the oldest element of the highest non-empty level.
+    pub fn pop(&mut self) -> Option<T> {
+        self.pop_with_priority().map(|(_priority, value)| value)
+    }
+    pub fn pop_with_priority(&mut self) -> Option<(usize, T)> {
+        let priority = self.top_priority()?;
+        let level = &mut self.levels[priority];
+        let value = level.pop_front()?;
+        if level.is_empty() {
+            self.mask &= !(1 << priority);
+        }
+        self.len -= 1;
+        Some((priority, value))
+    }
+}
+impl<T: fmt::Debug, const P: usize, const N: usize> fmt::Debug for PriorityFifo<T, P, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.levels, f)
+    }
+}
@@ -0,0 +1,357 @@
+//! Range Set and Range Map
+//!
+//! Half-open ranges are kept sorted and disjoint in a `Vec`, so lookups are
+//! binary searches and updates shift at most the tail of the storage.
+
+use crate::string::CapacityError;
+use crate::vec::Vec;
+use core::borrow::Borrow;
+use core::convert::AsRef;
+use core::fmt;
+use core::ops::{self, Range};
+
+/// Replaces `vec[span]` with `items`; the caller has checked the capacity.
+fn splice<T, const N: usize>(vec: &mut Vec<T, N>, span: Range<usize>, items: [Option<T>; 3]) {
+    let len = vec.len();
+    let count = span.end - span.start;
+    vec[span.start..].rotate_left(count);
+    vec.truncate(len - count);
+    for (pos, item) in (span.start..).zip(IntoIterator::into_iter(items).flatten()) {
+        if vec.push(item).is_err() {
+            unreachable!();
+        }
+        vec[pos..].rotate_right(1);
+    }
+}
+
+fn fits<T, const N: usize>(vec: &Vec<T, N>, span: &Range<usize>, items: &[Option<T>; 3]) -> bool {
+    let count = items.iter().filter(|item| item.is_some()).count();
+    vec.len() - (span.end - span.start) + count <= vec.capacity()
+}
+
+/// Uncovered parts of a range, in order.
+pub struct Gaps<Idx, I> {
+    ranges: I,
+    cursor: Idx,
+    end: Idx,
+}
+impl<'a, Idx: Ord + Clone + 'a, I: Iterator<Item = &'a Range<Idx>>> Iterator for Gaps<Idx, I> {
+    type Item = Range<Idx>;
+
+    fn next(&mut self) -> Option<Range<Idx>> {
+        while self.cursor < self.end {
+            match self.ranges.next() {
+                Some(range) if range.start < self.end => {
+                    if range.start > self.cursor {
+                        let gap = self.cursor.clone()..range.start.clone();
+                        self.cursor = range.end.clone();
+                        return Some(gap);
+                    }
+                    if range.end > self.cursor {
+                        self.cursor = range.end.clone();
+                    }
+                }
+                _ => {
+                    let gap = self.cursor.clone()..self.end.clone();
+                    self.cursor = self.end.clone();
+                    return Some(gap);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Sorted set of disjoint, non-adjacent half-open ranges.
+pub struct RangeSet<Idx, const N: usize> {
+    ranges: Vec<Range<Idx>, N>,
+}
+impl<Idx, const N: usize> Default for RangeSet<Idx, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<Idx, const N: usize> RangeSet<Idx, N> {
+    const CAPACITY: usize = N;
+    pub const fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn is_full(&self) -> bool {
+        self.len() == Self::CAPACITY
+    }
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+}
+impl<Idx: Ord + Clone, const N: usize> RangeSet<Idx, N> {
+    /// Merges `range` with every range it overlaps or touches.
+    pub fn insert(&mut self, range: Range<Idx>) -> Result<(), CapacityError> {
+        if range.is_empty() {
+            return Ok(());
+        }
+        let i = self.ranges.partition_point(|r| r.end < range.start);
+        let j = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if i < j {
+            if self.ranges[i].start < merged.start {
+                merged.start = self.ranges[i].start.clone();
+            }
+            if self.ranges[j - 1].end > merged.end {
+                merged.end = self.ranges[j - 1].end.clone();
+            }
+        }
+        let items = [Some(merged), None, None];
+        if !fits(&self.ranges, &(i..j), &items) {
+            return Err(CapacityError);
+        }
+        splice(&mut self.ranges, i..j, items);
+        Ok(())
+    }
+    /// Fails if cutting `range` out of the middle of a stored range would
+    /// need one more slot than is left.
+    pub fn remove(&mut self, range: Range<Idx>) -> Result<(), CapacityError> {
+        if range.is_empty() {
+            return Ok(());
+        }
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        let j = self.ranges.partition_point(|r| r.start < range.end);
+        if i >= j {
+            return Ok(());
+        }
+        let first = &self.ranges[i];
+        let last = &self.ranges[j - 1];
+        let left = if first.start < range.start {
+            Some(first.start.clone()..range.start)
+        } else {
+            None
+        };
+        let right = if last.end > range.end {
+            Some(range.end..last.end.clone())
+        } else {
+            None
+        };
+        let items = [left, right, None];
+        if !fits(&self.ranges, &(i..j), &items) {
+            return Err(CapacityError);
+        }
+        splice(&mut self.ranges, i..j, items);
+        Ok(())
+    }
+    pub fn contains(&self, point: &Idx) -> bool {
+        let i = self.ranges.partition_point(|r| &r.end <= point);
+        matches!(self.ranges.get(i), Some(r) if &r.start <= point)
+    }
+    pub fn overlaps(&self, range: &Range<Idx>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        matches!(self.ranges.get(i), Some(r) if r.start < range.end)
+    }
+    pub fn gaps(&self, within: Range<Idx>) -> Gaps<Idx, impl Iterator<Item = &Range<Idx>>> {
+        let i = self.ranges.partition_point(|r| r.end <= within.start);
+        Gaps {
+            ranges: self.ranges[i..].iter(),
+            cursor: within.start,
+            end: within.end,
+        }
+    }
+}
+
+impl<Idx, const N: usize> ops::Deref for RangeSet<Idx, N> {
+    type Target = [Range<Idx>];
+
+    fn deref(&self) -> &[Range<Idx>] {
+        self.ranges.deref()
+    }
+}
+impl<Idx, const N: usize> AsRef<[Range<Idx>]> for RangeSet<Idx, N> {
+    fn as_ref(&self) -> &[Range<Idx>] {
+        self
+    }
+}
+impl<Idx, const N: usize> Borrow<[Range<Idx>]> for RangeSet<Idx, N> {
+    fn borrow(&self) -> &[Range<Idx>] {
+        &self[..]
+    }
+}
+impl<Idx: fmt::Debug, const N: usize> fmt::Debug for RangeSet<Idx, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Sorted map from disjoint half-open ranges to values.
+///
+/// Adjacent ranges with equal values are coalesced.
+pub struct RangeMap<Idx, V, const N: usize> {
+    entries: Vec<(Range<Idx>, V), N>,
+}
+impl<Idx, V, const N: usize> Default for RangeMap<Idx, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<Idx, V, const N: usize> RangeMap<Idx, V, N> {
+    const CAPACITY: usize = N;
+    pub const fn new() -> Self {
+        RangeMap {
+            entries: Vec::new(),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn is_full(&self) -> bool {
+        self.len() == Self::CAPACITY
+    }
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+    pub fn ranges(&self) -> impl Iterator<Item = &Range<Idx>> {
+        self.entries.iter().map(|(r, _v)| r)
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_r, v)| v)
+    }
+}
+impl<Idx: Ord + Clone, V, const N: usize> RangeMap<Idx, V, N> {
+    pub fn get_key_value(&self, point: &Idx) -> Option<(&Range<Idx>, &V)> {
+        let i = self.entries.partition_point(|(r, _v)| &r.end <= point);
+        match self.entries.get(i) {
+            Some((r, v)) if &r.start <= point => Some((r, v)),
+            _ => None,
+        }
+    }
+    pub fn get(&self, point: &Idx) -> Option<&V> {
+        self.get_key_value(point).map(|(_r, v)| v)
+    }
+    pub fn contains_key(&self, point: &Idx) -> bool {
+        self.get_key_value(point).is_some()
+    }
+    pub fn overlaps(&self, range: &Range<Idx>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        let i = self.entries.partition_point(|(r, _v)| r.end <= range.start);
+        matches!(self.entries.get(i), Some((r, _v)) if r.start < range.end)
+    }
+    pub fn gaps(&self, within: Range<Idx>) -> Gaps<Idx, impl Iterator<Item = &Range<Idx>>> {
+        let i = self
+            .entries
+            .partition_point(|(r, _v)| r.end <= within.start);
+        Gaps {
+            ranges: self.entries[i..].iter().map(|(r, _v)| r),
+            cursor: within.start,
+            end: within.end,
+        }
+    }
+}
+impl<Idx: Ord + Clone, V: Clone + Eq, const N: usize> RangeMap<Idx, V, N> {
+    /// Maps every point of `range` to `value`, overwriting what was there.
+    pub fn insert(&mut self, range: Range<Idx>, value: V) -> Result<(), CapacityError> {
+        if range.is_empty() {
+            return Ok(());
+        }
+        let i = self.entries.partition_point(|(r, _v)| r.end <= range.start);
+        let j = self.entries.partition_point(|(r, _v)| r.start < range.end);
+        let (mut lo, mut hi) = (i, j);
+        let mut merged = range;
+        let mut left = None;
+        let mut right = None;
+        if i < j && self.entries[i].0.start < merged.start {
+            let (r, v) = &self.entries[i];
+            if *v == value {
+                merged.start = r.start.clone();
+            } else {
+                left = Some((r.start.clone()..merged.start.clone(), v.clone()));
+            }
+        } else if i > 0 {
+            let (r, v) = &self.entries[i - 1];
+            if r.end == merged.start && *v == value {
+                merged.start = r.start.clone();
+                lo = i - 1;
+            }
+        }
+        if i < j && self.entries[j - 1].0.end > merged.end {
+            let (r, v) = &self.entries[j - 1];
+            if *v == value {
+                merged.end = r.end.clone();
+            } else {
+                right = Some((merged.end.clone()..r.end.clone(), v.clone()));
+            }
+        } else if let Some((r, v)) = self.entries.get(j) {
+            if r.start == merged.end && *v == value {
+                merged.end = r.end.clone();
+                hi = j + 1;
+            }
+        }
+        let items = [left, Some((merged, value)), right];
+        if !fits(&self.entries, &(lo..hi), &items) {
+            return Err(CapacityError);
+        }
+        splice(&mut self.entries, lo..hi, items);
+        Ok(())
+    }
+    /// Fails if cutting `range` out of the middle of a stored range would
+    /// need one more slot than is left.
+    pub fn remove(&mut self, range: Range<Idx>) -> Result<(), CapacityError> {
+        if range.is_empty() {
+            return Ok(());
+        }
+        let i = self.entries.partition_point(|(r, _v)| r.end <= range.start);
+        let j = self.entries.partition_point(|(r, _v)| r.start < range.end);
+        if i >= j {
+            return Ok(());
+        }
+        let (first, first_value) = &self.entries[i];
+        let (last, last_value) = &self.entries[j - 1];
+        let left = if first.start < range.start {
+            Some((
+                first.start.clone()..range.start.clone(),
+                first_value.clone(),
+            ))
+        } else {
+            None
+        };
+        let right = if last.end > range.end {
+            Some((range.end.clone()..last.end.clone(), last_value.clone()))
+        } else {
+            None
+        };
+        let items = [left, right, None];
+        if !fits(&self.entries, &(i..j), &items) {
+            return Err(CapacityError);
+        }
+        splice(&mut self.entries, i..j, items);
+        Ok(())
+    }
+}
+
+impl<Idx, V, const N: usize> ops::Deref for RangeMap<Idx, V, N> {
+    type Target = [(Range<Idx>, V)];
+
+    fn deref(&self) -> &[(Range<Idx>, V)] {
+        self.entries.deref()
+    }
+}
+impl<Idx, V, const N: usize> AsRef<[(Range<Idx>, V)]> for RangeMap<Idx, V, N> {
+    fn as_ref(&self) -> &[(Range<Idx>, V)] {
+        self
+    }
+}
+impl<Idx, V, const N: usize> Borrow<[(Range<Idx>, V)]> for RangeMap<Idx, V, N> {
+    fn borrow(&self) -> &[(Range<Idx>, V)] {
+        &self[..]
+    }
+}
+impl<Idx: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for RangeMap<Idx, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(r, v)| (r, v)))
+            .finish()
+    }
+}
@@ -0,0 +1,153 @@
+//! Variable-length record queue
+//!
+//! Records are stored back to back in a byte ring, each after a
+//! little-endian `u16` length header. A record may wrap around the end.
+
+use crate::string::CapacityError;
+use core::fmt;
+
+const HEADER: usize = 2;
+
+pub struct RecordQueue<const N: usize> {
+    buf: [u8; N],
+    /// Offset of the first header.
+    start: usize,
+    /// Bytes in use, headers included.
+    used: usize,
+    /// Number of records.
+    count: usize,
+}
+impl<const N: usize> Default for RecordQueue<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: usize> RecordQueue<N> {
+    const CAPACITY: usize = N;
+    pub const fn new() -> Self {
+        RecordQueue {
+            buf: [0; N],
+            start: 0,
+            used: 0,
+            count: 0,
+        }
+    }
+    /// Capacity in bytes, headers included.
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    /// Number of records.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    /// Largest record that `push` would accept right now.
+    pub fn available(&self) -> usize {
+        Self::CAPACITY
+            .checked_sub(self.used)
+            .and_then(|free| free.checked_sub(HEADER))
+            .map_or(0, |len| len.min(u16::MAX as usize))
+    }
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.used = 0;
+        self.count = 0;
+    }
+    #[inline]
+    fn wrap(&self, idx: usize) -> usize {
+        if idx >= Self::CAPACITY {
+            idx - Self::CAPACITY
+        } else {
+            idx
+        }
+    }
+    fn write_at(&mut self, off: usize, data: &[u8]) {
+        let first = data.len().min(Self::CAPACITY - off);
+        self.buf[off..off + first].copy_from_slice(&data[..first]);
+        self.buf[..data.len() - first].copy_from_slice(&data[first..]);
+    }
+    fn slices_at(&self, off: usize, len: usize) -> (&[u8], &[u8]) {
+        let first = len.min(Self::CAPACITY - off);
+        (&self.buf[off..off + first], &self.buf[..len - first])
+    }
+    fn record_len(&self, off: usize) -> usize {
+        let (a, b) = self.slices_at(off, HEADER);
+        let mut header = [0; HEADER];
+        header[..a.len()].copy_from_slice(a);
+        header[a.len()..].copy_from_slice(b);
+        u16::from_le_bytes(header) as usize
+    }
+    /// Appends a record, or nothing at all if it does not fit.
+    pub fn push(&mut self, record: &[u8]) -> Result<(), CapacityError> {
+        // The header needs room even for an empty record.
+        let free = Self::CAPACITY - self.used;
+        if record.len() > u16::MAX as usize || HEADER + record.len() > free {
+            return Err(CapacityError);
+        }
+        let end = self.wrap(self.start + self.used);
+        self.write_at(end, &(record.len() as u16).to_le_bytes());
+        self.write_at(self.wrap(end + HEADER), record);
+        self.used += HEADER + record.len();
+        self.count += 1;
+        Ok(())
+    }
+    /// The oldest record, as two slices when it wraps around the end.
+    pub fn peek(&self) -> Option<(&[u8], &[u8])> {
+        if self.is_empty() {
+            return None;
+        }
+        let len = self.record_len(self.start);
+        Some(self.slices_at(self.wrap(self.start + HEADER), len))
+    }
+    /// Removes the oldest record. The returned slices stay valid until the next mutation.
+    pub fn pop(&mut self) -> Option<(&[u8], &[u8])> {
+        if self.is_empty() {
+            return None;
+        }
+        let start = self.start;
+        let len = self.record_len(start);
+        self.count -= 1;
+        if self.count == 0 {
+            self.start = 0;
+            self.used = 0;
+        } else {
+            self.start = self.wrap(start + HEADER + len);
+            self.used -= HEADER + len;
+        }
+        Some(self.slices_at(self.wrap(start + HEADER), len))
+    }
+    /// Copies the oldest record into `buf` and removes it, returning its length.
+    ///
+    /// Fails without removing anything if `buf` is too small.
+    pub fn pop_into(&mut self, buf: &mut [u8]) -> Result<Option<usize>, CapacityError> {
+        let (a, b) = match self.peek() {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        let len = a.len() + b.len();
+        if len > buf.len() {
+            return Err(CapacityError);
+        }
+        buf[..a.len()].copy_from_slice(a);
+        buf[a.len()..len].copy_from_slice(b);
+        self.pop();
+        Ok(Some(len))
+    }
+    /// Records from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], &[u8])> {
+        let mut off = self.start;
+        (0..self.count).map(move |_| {
+            let len = self.record_len(off);
+            let record = self.slices_at(self.wrap(off + HEADER), len);
+            off = self.wrap(off + HEADER + len);
+            record
+        })
+    }
+}
+impl<const N: usize> fmt::Debug for RecordQueue<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,206 @@
+//! Slab
+//!
+//! Slots are handed out by key and reused through a free list,
+//! so keys of other entries stay valid across removals.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// Key returned by `Slab::insert`.
+pub trait SlabKey: Copy {
+    fn new(index: usize, generation: u32) -> Self;
+    fn index(&self) -> usize;
+    /// `None` skips the generation check.
+    fn generation(&self) -> Option<u32>;
+}
+impl SlabKey for usize {
+    fn new(index: usize, _generation: u32) -> Self {
+        index
+    }
+    fn index(&self) -> usize {
+        *self
+    }
+    fn generation(&self) -> Option<u32> {
+        None
+    }
+}
+/// Key that no longer matches once its slot is removed and reused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GenKey {
+    index: usize,
+    generation: u32,
+}
+impl SlabKey for GenKey {
+    fn new(index: usize, generation: u32) -> Self {
+        GenKey { index, generation }
+    }
+    fn index(&self) -> usize {
+        self.index
+    }
+    fn generation(&self) -> Option<u32> {
+        Some(self.generation)
+    }
+}
+
+const OCCUPIED: usize = usize::MAX;
+
+pub struct Slab<T, const N: usize, K = usize> {
+    buf: MaybeUninit<[T; N]>,
+    /// `OCCUPIED`, or the next free slot (`N` ends the list).
+    links: [usize; N],
+    generations: [u32; N],
+    free: usize,
+    /// Slots from here on have never been used.
+    unused: usize,
+    len: usize,
+    _key: PhantomData<K>,
+}
+impl<T, const N: usize, K> Default for Slab<T, N, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize, K> Slab<T, N, K> {
+    const CAPACITY: usize = N;
+    pub const fn new() -> Self {
+        Slab {
+            buf: MaybeUninit::uninit(),
+            links: [0; N],
+            generations: [0; N],
+            free: N,
+            unused: 0,
+            len: 0,
+            _key: PhantomData,
+        }
+    }
+    fn ptr(&self) -> *mut T {
+        self.buf.as_ptr() as *mut T
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn is_full(&self) -> bool {
+        self.len == Self::CAPACITY
+    }
+    fn is_occupied(&self, index: usize) -> bool {
+        index < self.unused && self.links[index] == OCCUPIED
+    }
+    pub fn clear(&mut self) {
+        for index in 0..self.unused {
+            if self.links[index] == OCCUPIED {
+                self.generations[index] = self.generations[index].wrapping_add(1);
+                unsafe { ptr::drop_in_place(self.ptr().add(index)) };
+            }
+        }
+        // Rebuild the free list so recycled slots keep their generation.
+        self.free = Self::CAPACITY;
+        for index in (0..self.unused).rev() {
+            self.links[index] = self.free;
+            self.free = index;
+        }
+        self.len = 0;
+    }
+}
+impl<T, const N: usize, K: SlabKey> Slab<T, N, K> {
+    fn key_index(&self, key: K) -> Option<usize> {
+        let index = key.index();
+        if !self.is_occupied(index) {
+            return None;
+        }
+        match key.generation() {
+            Some(generation) if generation != self.generations[index] => None,
+            _ => Some(index),
+        }
+    }
+    /// Key of the occupied slot at `index`.
+    pub fn key_at(&self, index: usize) -> Option<K> {
+        if self.is_occupied(index) {
+            Some(K::new(index, self.generations[index]))
+        } else {
+            None
+        }
+    }
+    pub fn contains(&self, key: K) -> bool {
+        self.key_index(key).is_some()
+    }
+    pub fn get(&self, key: K) -> Option<&T> {
+        let index = self.key_index(key)?;
+        unsafe { Some(&*self.ptr().add(index)) }
+    }
+    pub fn get_mut(&mut self, key: K) -> Option<&mut T> {
+        let index = self.key_index(key)?;
+        unsafe { Some(&mut *self.ptr().add(index)) }
+    }
+    /// Key that the next `insert` will return.
+    pub fn vacant_key(&self) -> Option<K> {
+        let index = if self.free < Self::CAPACITY {
+            self.free
+        } else if self.unused < Self::CAPACITY {
+            self.unused
+        } else {
+            return None;
+        };
+        Some(K::new(index, self.generations[index]))
+    }
+    pub fn insert(&mut self, value: T) -> Result<K, T> {
+        let index = if self.free < Self::CAPACITY {
+            let index = self.free;
+            self.free = self.links[index];
+            index
+        } else if self.unused < Self::CAPACITY {
+            self.unused += 1;
+            self.unused - 1
+        } else {
+            return Err(value);
+        };
+        unsafe { ptr::write(self.ptr().add(index), value) };
+        self.links[index] = OCCUPIED;
+        self.len += 1;
+        Ok(K::new(index, self.generations[index]))
+    }
+    pub fn remove(&mut self, key: K) -> Option<T> {
+        let index = self.key_index(key)?;
+        self.links[index] = self.free;
+        self.free = index;
+        self.generations[index] = self.generations[index].wrapping_add(1);
+        self.len -= 1;
+        unsafe { Some(ptr::read(self.ptr().add(index))) }
+    }
+    /// Iterates over occupied slots in index order.
+    pub fn iter(&self) -> impl Iterator<Item = (K, &T)> {
+        (0..self.unused)
+            .filter(move |index| self.links[*index] == OCCUPIED)
+            .map(move |index| unsafe {
+                (
+                    K::new(index, self.generations[index]),
+                    &*self.ptr().add(index),
+                )
+            })
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (K, &mut T)> {
+        let ptr = self.ptr();
+        let links = &self.links;
+        let generations = &self.generations;
+        (0..self.unused)
+            .filter(move |index| links[*index] == OCCUPIED)
+            .map(move |index| unsafe { (K::new(index, generations[index]), &mut *ptr.add(index)) })
+    }
+}
+impl<T: fmt::Debug, const N: usize, K: SlabKey + fmt::Debug> fmt::Debug for Slab<T, N, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+impl<T, const N: usize, K> Drop for Slab<T, N, K> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
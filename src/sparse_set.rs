@@ -0,0 +1,115 @@
+//! Sparse set
+//!
+//! A sparse table maps ids in `0..N` to positions in a dense `Vec`,
+//! giving O(1) lookup and tightly packed iteration.
+
+use crate::vec::Vec;
+use core::borrow::Borrow;
+use core::convert::AsRef;
+use core::fmt;
+use core::mem;
+use core::ops;
+
+const NONE: usize = usize::MAX;
+
+pub struct SparseSet<T, const N: usize> {
+    sparse: [usize; N],
+    dense: Vec<(usize, T), N>,
+}
+impl<T, const N: usize> Default for SparseSet<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> SparseSet<T, N> {
+    const CAPACITY: usize = N;
+    pub const fn new() -> Self {
+        SparseSet {
+            sparse: [NONE; N],
+            dense: Vec::new(),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+    pub fn clear(&mut self) {
+        for (id, _value) in self.dense.iter() {
+            self.sparse[*id] = NONE;
+        }
+        self.dense.clear();
+    }
+    /// Position of `id` in the dense slice.
+    pub fn get_index(&self, id: usize) -> Option<usize> {
+        match self.sparse.get(id) {
+            Some(&index) if index != NONE => Some(index),
+            _ => None,
+        }
+    }
+    pub fn contains(&self, id: usize) -> bool {
+        self.get_index(id).is_some()
+    }
+    pub fn get(&self, id: usize) -> Option<&T> {
+        let index = self.get_index(id)?;
+        Some(&self.dense[index].1)
+    }
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut T> {
+        let index = self.get_index(id)?;
+        Some(&mut self.dense[index].1)
+    }
+    /// Fails if `id` is not in `0..N`.
+    pub fn insert(&mut self, id: usize, value: T) -> Result<Option<T>, (usize, T)> {
+        if id >= Self::CAPACITY {
+            return Err((id, value));
+        }
+        if let Some(index) = self.get_index(id) {
+            return Ok(Some(mem::replace(&mut self.dense[index].1, value)));
+        }
+        self.dense.push((id, value))?;
+        self.sparse[id] = self.dense.len() - 1;
+        Ok(None)
+    }
+    /// Moves the last dense entry into the hole.
+    pub fn remove(&mut self, id: usize) -> Option<T> {
+        let index = self.get_index(id)?;
+        self.sparse[id] = NONE;
+        let (_id, value) = self.dense.swap_remove(index);
+        if let Some((moved, _value)) = self.dense.get(index) {
+            self.sparse[*moved] = index;
+        }
+        Some(value)
+    }
+    pub fn ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.dense.iter().map(|(id, _value)| *id)
+    }
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.dense.iter().map(|(_id, value)| value)
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.dense.iter_mut().map(|(_id, value)| value)
+    }
+}
+
+impl<T, const N: usize> ops::Deref for SparseSet<T, N> {
+    type Target = [(usize, T)];
+
+    fn deref(&self) -> &[(usize, T)] {
+        self.dense.deref()
+    }
+}
+impl<T, const N: usize> AsRef<[(usize, T)]> for SparseSet<T, N> {
+    fn as_ref(&self) -> &[(usize, T)] {
+        self
+    }
+}
+impl<T, const N: usize> Borrow<[(usize, T)]> for SparseSet<T, N> {
+    fn borrow(&self) -> &[(usize, T)] {
+        &self[..]
+    }
+}
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SparseSet<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(id, value)| (id, value)))
+            .finish()
+    }
+}
@@ -0,0 +1,59 @@
+//! Backing storage
+//!
+//! Containers such as `VecInner<T, S>` keep their elements in `S`:
+//!
+//! - `[MaybeUninit<T>; N]` is stored inline and fixes the capacity in the type.
+//!   `Vec<T, N>` and friends are aliases for this case.
+//! - `[MaybeUninit<T>]` carries the capacity at runtime. Because the storage is
+//!   the last field, `&Vec<T, N>` coerces to `&VecView<T>`.
+//! - `&mut [MaybeUninit<T>]` borrows a buffer that lives elsewhere, e.g. in a
+//!   linker section or DMA-capable RAM, see `VecInner::from_storage`.
+//!
+//! ```
+//! use core::mem::MaybeUninit;
+//! use fixed_queue::BorrowedVec;
+//!
+//! let mut buf = [MaybeUninit::<u8>::uninit(); 4];
+//! let mut vec = BorrowedVec::from_storage(&mut buf[..]);
+//! assert!(vec.push(1).is_ok());
+//! assert_eq!(vec.capacity(), 4);
+//! ```
+
+use core::mem::MaybeUninit;
+
+/// Uninitialized slots a container writes its elements into.
+///
+/// Implement it for your own buffer type if none of the provided ones fit.
+///
+/// # Safety
+///
+/// Both methods must return the same slots every time they are called,
+/// so the length never changes and initialized slots stay initialized.
+pub unsafe trait Storage<T> {
+    fn buffer(&self) -> &[MaybeUninit<T>];
+    fn buffer_mut(&mut self) -> &mut [MaybeUninit<T>];
+}
+unsafe impl<T, const N: usize> Storage<T> for [MaybeUninit<T>; N] {
+    fn buffer(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+    fn buffer_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+unsafe impl<T> Storage<T> for [MaybeUninit<T>] {
+    fn buffer(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+    fn buffer_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+unsafe impl<T> Storage<T> for &mut [MaybeUninit<T>] {
+    fn buffer(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+    fn buffer_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
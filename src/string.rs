@@ -0,0 +1,254 @@
+//! String
+
+use crate::vec::Vec;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::convert::{AsMut, AsRef, TryFrom};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+use core::{fmt, ptr, str};
+
+/// The value does not fit in the remaining capacity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityError;
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+pub struct String<const N: usize> {
+    vec: Vec<u8, N>,
+}
+impl<const N: usize> Default for String<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: usize> String<N> {
+    pub const fn new() -> Self {
+        String { vec: Vec::new() }
+    }
+    pub fn from_utf8(vec: Vec<u8, N>) -> Result<Self, str::Utf8Error> {
+        str::from_utf8(&vec)?;
+        Ok(String { vec })
+    }
+    /// # Safety
+    ///
+    /// `vec` must be valid UTF-8.
+    pub unsafe fn from_utf8_unchecked(vec: Vec<u8, N>) -> Self {
+        String { vec }
+    }
+    pub fn into_bytes(self) -> Vec<u8, N> {
+        self.vec
+    }
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.vec) }
+    }
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(&mut self.vec) }
+    }
+    /// # Safety
+    ///
+    /// The bytes must stay valid UTF-8.
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<u8, N> {
+        &mut self.vec
+    }
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.len()
+    }
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+    pub fn push(&mut self, ch: char) -> Result<(), char> {
+        let mut buf = [0; 4];
+        self.push_str(ch.encode_utf8(&mut buf)).map_err(|_| ch)
+    }
+    /// Appends all of `string` or nothing.
+    pub fn push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        if string.len() > self.remaining() {
+            return Err(CapacityError);
+        }
+        self.vec.extend_from_slice(string.as_bytes());
+        Ok(())
+    }
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        self.vec.truncate(self.len() - ch.len_utf8());
+        Some(ch)
+    }
+    /// Panics if `new_len` is not on a char boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            assert!(self.is_char_boundary(new_len));
+            self.vec.truncate(new_len);
+        }
+    }
+    /// Panics if `idx` is not on a char boundary.
+    pub fn insert(&mut self, idx: usize, ch: char) -> Result<(), char> {
+        let mut buf = [0; 4];
+        self.insert_str(idx, ch.encode_utf8(&mut buf))
+            .map_err(|_| ch)
+    }
+    /// Panics if `idx` is not on a char boundary.
+    pub fn insert_str(&mut self, idx: usize, string: &str) -> Result<(), CapacityError> {
+        assert!(self.is_char_boundary(idx));
+        if string.len() > self.remaining() {
+            return Err(CapacityError);
+        }
+        let len = self.len();
+        let amt = string.len();
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            ptr::copy(ptr.add(idx), ptr.add(idx + amt), len - idx);
+            ptr::copy_nonoverlapping(string.as_ptr(), ptr.add(idx), amt);
+            self.vec.set_len(len + amt);
+        }
+        Ok(())
+    }
+    /// Panics if `idx` is not on a char boundary or out of bounds.
+    pub fn remove(&mut self, idx: usize) -> char {
+        let ch = match self[idx..].chars().next() {
+            Some(ch) => ch,
+            None => panic!("cannot remove a char from the end of a string"),
+        };
+        let len = self.len();
+        let amt = ch.len_utf8();
+        self.vec.copy_within(idx + amt..len, idx);
+        self.vec.truncate(len - amt);
+        ch
+    }
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        let mut idx = 0;
+        while let Some(ch) = self[idx..].chars().next() {
+            if f(ch) {
+                idx += ch.len_utf8();
+            } else {
+                self.remove(idx);
+            }
+        }
+    }
+}
+
+impl<const N: usize> Deref for String<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl<const N: usize> DerefMut for String<N> {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+impl<const N: usize> AsRef<str> for String<N> {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+impl<const N: usize> AsMut<str> for String<N> {
+    fn as_mut(&mut self) -> &mut str {
+        self
+    }
+}
+impl<const N: usize> AsRef<[u8]> for String<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+impl<const N: usize> Borrow<str> for String<N> {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+impl<const N: usize> BorrowMut<str> for String<N> {
+    fn borrow_mut(&mut self) -> &mut str {
+        self
+    }
+}
+impl<const N: usize> TryFrom<&str> for String<N> {
+    type Error = CapacityError;
+
+    fn try_from(string: &str) -> Result<Self, CapacityError> {
+        let mut s = String::new();
+        s.push_str(string)?;
+        Ok(s)
+    }
+}
+impl<const N: usize> str::FromStr for String<N> {
+    type Err = CapacityError;
+
+    fn from_str(string: &str) -> Result<Self, CapacityError> {
+        String::try_from(string)
+    }
+}
+impl<const N: usize> Clone for String<N> {
+    fn clone(&self) -> Self {
+        let mut s = String::new();
+        s.vec.extend_from_slice(self.as_bytes());
+        s
+    }
+}
+impl<const N: usize> fmt::Write for String<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c).map_err(|_| fmt::Error)
+    }
+}
+impl<const N: usize> fmt::Display for String<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+impl<const N: usize> fmt::Debug for String<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+impl<const N: usize, const M: usize> PartialEq<String<M>> for String<N> {
+    fn eq(&self, other: &String<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+impl<const N: usize> Eq for String<N> {}
+impl<const N: usize> PartialEq<str> for String<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+impl<const N: usize> PartialEq<&str> for String<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+impl<const N: usize> PartialEq<String<N>> for str {
+    fn eq(&self, other: &String<N>) -> bool {
+        self == other.as_str()
+    }
+}
+impl<const N: usize> PartialEq<String<N>> for &str {
+    fn eq(&self, other: &String<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+impl<const N: usize, const M: usize> PartialOrd<String<M>> for String<N> {
+    fn partial_cmp(&self, other: &String<M>) -> Option<Ordering> {
+        Some(self.as_str().cmp(other.as_str()))
+    }
+}
+impl<const N: usize> Ord for String<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+impl<const N: usize> Hash for String<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
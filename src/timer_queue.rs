@@ -0,0 +1,120 @@
+//! Timer queue
+//!
+//! Payloads live in a `Slab`, their deadlines in an `IndexedPriorityQueue`
+//! keyed by slab index, so insert, cancel and reschedule are O(log n).
+
+use crate::indexed_priority_queue::IndexedPriorityQueue;
+use crate::slab::{GenKey, Slab, SlabKey};
+use core::fmt;
+
+/// Refers to a scheduled timer, stale once it fires or is cancelled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerHandle(GenKey);
+
+pub struct TimerQueue<T, const N: usize> {
+    payloads: Slab<T, N, GenKey>,
+    deadlines: IndexedPriorityQueue<u64, N>,
+}
+impl<T, const N: usize> Default for TimerQueue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> TimerQueue<T, N> {
+    pub const fn new() -> Self {
+        TimerQueue {
+            payloads: Slab::new(),
+            deadlines: IndexedPriorityQueue::new(),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.payloads.capacity()
+    }
+    pub fn len(&self) -> usize {
+        self.payloads.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.payloads.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.deadlines.clear();
+        self.payloads.clear();
+    }
+    /// Schedules `payload` to expire once the caller's tick reaches `deadline`.
+    pub fn schedule(&mut self, deadline: u64, payload: T) -> Result<TimerHandle, T> {
+        let key = self.payloads.insert(payload)?;
+        let pushed = self.deadlines.push(key.index(), deadline).is_ok();
+        debug_assert!(pushed);
+        Ok(TimerHandle(key))
+    }
+    pub fn cancel(&mut self, handle: TimerHandle) -> Option<T> {
+        let payload = self.payloads.remove(handle.0)?;
+        self.deadlines.remove(handle.0.index());
+        Some(payload)
+    }
+    /// Moves a pending timer to a new deadline, returns `false` if it is stale.
+    pub fn reschedule(&mut self, handle: TimerHandle, deadline: u64) -> bool {
+        if self.payloads.contains(handle.0) {
+            self.deadlines.change_priority(handle.0.index(), deadline);
+            true
+        } else {
+            false
+        }
+    }
+    pub fn deadline(&self, handle: TimerHandle) -> Option<u64> {
+        if self.payloads.contains(handle.0) {
+            self.deadlines.priority(handle.0.index()).copied()
+        } else {
+            None
+        }
+    }
+    pub fn get(&self, handle: TimerHandle) -> Option<&T> {
+        self.payloads.get(handle.0)
+    }
+    pub fn get_mut(&mut self, handle: TimerHandle) -> Option<&mut T> {
+        self.payloads.get_mut(handle.0)
+    }
+    /// Earliest pending deadline.
+    pub fn next_deadline(&self) -> Option<u64> {
+        self.deadlines.peek().map(|(_id, deadline)| *deadline)
+    }
+    /// Removes the earliest timer if it expired by `now`.
+    pub fn pop_expired(&mut self, now: u64) -> Option<(u64, T)> {
+        match self.deadlines.peek() {
+            Some((_id, deadline)) if *deadline <= now => {}
+            _ => return None,
+        }
+        let (id, deadline) = self.deadlines.pop()?;
+        let key = self.payloads.key_at(id)?;
+        self.payloads.remove(key).map(|payload| (deadline, payload))
+    }
+    /// Removes timers that expired by `now`, earliest first.
+    pub fn poll_expired(&mut self, now: u64) -> PollExpired<'_, T, N> {
+        PollExpired { queue: self, now }
+    }
+}
+
+pub struct PollExpired<'a, T, const N: usize> {
+    queue: &'a mut TimerQueue<T, N>,
+    now: u64,
+}
+impl<T, const N: usize> Iterator for PollExpired<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.queue
+            .pop_expired(self.now)
+            .map(|(_deadline, payload)| payload)
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for TimerQueue<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.payloads.iter().map(|(k, v)| {
+                let deadline = self.deadlines.priority(k.index());
+                (deadline, v)
+            }))
+            .finish()
+    }
+}
@@ -0,0 +1,485 @@
+//! Traits shared by the containers
+//!
+//! They let code be written against "some bounded FIFO" or "some map"
+//! and switch the container without touching call sites.
+//!
+//! `RecordQueue` implements neither `FixedCapacity` nor `Fifo`: its capacity
+//! is counted in bytes but its length in records, and a record that wraps
+//! around the end cannot be handed out as a single `&Item`.
+
+use crate::binary_heap::BinaryHeap;
+use crate::bit_set::BitSet;
+use crate::bounded_map::{BoundedMap, EvictionPolicy};
+use crate::double_buffer::{DoubleBuffer, TripleBuffer};
+use crate::fixed_hash_map::FixedHashMap;
+use crate::fixed_index_map::FixedIndexMap;
+use crate::gap_buffer::{GapBuffer, GapString};
+use crate::history::HistoryInner;
+use crate::indexed_priority_queue::IndexedPriorityQueue;
+use crate::linear_map::LinearMapInner;
+use crate::linear_set::LinearSet;
+use crate::linked_list::LinkedList;
+use crate::lru_cache::LruCache;
+use crate::min_max_heap::MinMaxHeap;
+#[cfg(target_has_atomic = "ptr")]
+use crate::pool::Pool;
+use crate::priority_fifo::PriorityFifo;
+use crate::range_set::{RangeMap, RangeSet};
+use crate::slab::Slab;
+use crate::sparse_set::SparseSet;
+use crate::storage::Storage;
+use crate::string::String;
+use crate::timer_queue::TimerQueue;
+use crate::unique_queue::{HashedUniqueQueue, UniqueQueue};
+use crate::vec::VecInner;
+use crate::vec_deque::VecDequeInner;
+use core::hash::{BuildHasher, Hash};
+
+/// A container with a fixed upper bound on its length.
+pub trait FixedCapacity {
+    fn capacity(&self) -> usize;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn is_full(&self) -> bool {
+        self.len() >= self.capacity()
+    }
+    fn remaining(&self) -> usize {
+        self.capacity() - self.len()
+    }
+}
+
+/// First in, first out.
+pub trait Fifo: FixedCapacity {
+    type Item;
+    /// Gives the item back if the queue is full.
+    fn enqueue(&mut self, item: Self::Item) -> Result<(), Self::Item>;
+    fn dequeue(&mut self) -> Option<Self::Item>;
+    /// The item `dequeue` would return.
+    fn front(&self) -> Option<&Self::Item>;
+}
+
+/// Last in, first out.
+pub trait Lifo: FixedCapacity {
+    type Item;
+    /// Gives the item back if the stack is full.
+    fn push(&mut self, item: Self::Item) -> Result<(), Self::Item>;
+    fn pop(&mut self) -> Option<Self::Item>;
+    /// The item `pop` would return.
+    fn top(&self) -> Option<&Self::Item>;
+}
+
+/// Result of `MapLike::insert`.
+pub type Insert<K, V> = Result<Option<V>, (K, V)>;
+
+pub trait MapLike: FixedCapacity {
+    type Key;
+    type Value;
+    fn get(&self, key: &Self::Key) -> Option<&Self::Value>;
+    fn get_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Value>;
+    /// Returns the replaced value, or gives the entry back if the map is full.
+    fn insert(&mut self, key: Self::Key, value: Self::Value) -> Insert<Self::Key, Self::Value>;
+    fn remove(&mut self, key: &Self::Key) -> Option<Self::Value>;
+    fn contains_key(&self, key: &Self::Key) -> bool {
+        self.get(key).is_some()
+    }
+}
+
+// Slice-backed containers get `len` through `Deref`, which would resolve to
+// the trait method itself inside these impls, hence `self[..].len()`.
+
+impl<T, S: Storage<T> + ?Sized> FixedCapacity for VecInner<T, S> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self[..].len()
+    }
+}
+impl<T, S: Storage<T> + ?Sized> FixedCapacity for VecDequeInner<T, S> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, S: Storage<T> + ?Sized> FixedCapacity for HistoryInner<T, S> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self[..].len()
+    }
+}
+impl<K, V, S: Storage<(K, V)> + ?Sized> FixedCapacity for LinearMapInner<K, V, S> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self[..].len()
+    }
+}
+impl<T, const N: usize> FixedCapacity for LinearSet<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self[..].len()
+    }
+}
+impl<K, V, const N: usize, S> FixedCapacity for FixedHashMap<K, V, N, S> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<K, V, const N: usize, S> FixedCapacity for FixedIndexMap<K, V, N, S> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self[..].len()
+    }
+}
+impl<K, V, const N: usize> FixedCapacity for LruCache<K, V, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<K, V, P: EvictionPolicy<K, V>, const N: usize> FixedCapacity for BoundedMap<K, V, P, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, K, const N: usize> FixedCapacity for BinaryHeap<T, K, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<P, const N: usize> FixedCapacity for IndexedPriorityQueue<P, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, const N: usize> FixedCapacity for MinMaxHeap<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, const N: usize, K> FixedCapacity for Slab<T, N, K> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, const N: usize> FixedCapacity for LinkedList<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<const N: usize> FixedCapacity for String<N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+}
+impl<T, const N: usize> FixedCapacity for TimerQueue<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, const N: usize> FixedCapacity for UniqueQueue<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, const N: usize> FixedCapacity for HashedUniqueQueue<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+#[cfg(target_has_atomic = "ptr")]
+impl<T, const N: usize> FixedCapacity for Pool<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+/// Counts the write buffer.
+impl<T, const N: usize> FixedCapacity for DoubleBuffer<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+/// Counts the write buffer.
+impl<T, const N: usize> FixedCapacity for TripleBuffer<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, const P: usize, const N: usize> FixedCapacity for PriorityFifo<T, P, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<const WORDS: usize> FixedCapacity for BitSet<WORDS> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, const N: usize> FixedCapacity for SparseSet<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self[..].len()
+    }
+}
+impl<Idx, const N: usize> FixedCapacity for RangeSet<Idx, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self[..].len()
+    }
+}
+impl<Idx, V, const N: usize> FixedCapacity for RangeMap<Idx, V, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self[..].len()
+    }
+}
+impl<T, const N: usize> FixedCapacity for GapBuffer<T, N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<const N: usize> FixedCapacity for GapString<N> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S: Storage<T> + ?Sized> Fifo for VecDequeInner<T, S> {
+    type Item = T;
+    fn enqueue(&mut self, item: T) -> Result<(), T> {
+        self.push_back(item)
+    }
+    fn dequeue(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+    fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+}
+impl<T, const N: usize> Fifo for LinkedList<T, N> {
+    type Item = T;
+    fn enqueue(&mut self, item: T) -> Result<(), T> {
+        self.push_back(item).map(|_handle| ())
+    }
+    fn dequeue(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+    fn front(&self) -> Option<&T> {
+        self.front()
+    }
+}
+/// Enqueuing an item that is already queued succeeds without adding it again.
+impl<T: PartialEq, const N: usize> Fifo for UniqueQueue<T, N> {
+    type Item = T;
+    fn enqueue(&mut self, item: T) -> Result<(), T> {
+        self.push_back(item).map(|_added| ())
+    }
+    fn dequeue(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+    fn front(&self) -> Option<&T> {
+        self.front()
+    }
+}
+/// Enqueuing an item that is already queued succeeds without adding it again.
+impl<T: Hash + Eq, const N: usize> Fifo for HashedUniqueQueue<T, N> {
+    type Item = T;
+    fn enqueue(&mut self, item: T) -> Result<(), T> {
+        self.push_back(item).map(|_added| ())
+    }
+    fn dequeue(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+    fn front(&self) -> Option<&T> {
+        self.front()
+    }
+}
+/// Enqueues at the lowest priority, so items only ever enqueued through
+/// `Fifo` come out in order; `dequeue` still takes higher levels first.
+impl<T, const P: usize, const N: usize> Fifo for PriorityFifo<T, P, N> {
+    type Item = T;
+    fn enqueue(&mut self, item: T) -> Result<(), T> {
+        self.push(0, item)
+    }
+    fn dequeue(&mut self) -> Option<T> {
+        self.pop()
+    }
+    fn front(&self) -> Option<&T> {
+        self.peek()
+    }
+}
+
+impl<T, S: Storage<T> + ?Sized> Lifo for VecInner<T, S> {
+    type Item = T;
+    fn push(&mut self, item: T) -> Result<(), T> {
+        self.push(item)
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.pop()
+    }
+    fn top(&self) -> Option<&T> {
+        self.last()
+    }
+}
+impl<T, S: Storage<T> + ?Sized> Lifo for VecDequeInner<T, S> {
+    type Item = T;
+    fn push(&mut self, item: T) -> Result<(), T> {
+        self.push_back(item)
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+    fn top(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+}
+impl<T, const N: usize> Lifo for LinkedList<T, N> {
+    type Item = T;
+    fn push(&mut self, item: T) -> Result<(), T> {
+        self.push_back(item).map(|_handle| ())
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+    fn top(&self) -> Option<&T> {
+        self.back()
+    }
+}
+
+impl<K: PartialEq, V, S: Storage<(K, V)> + ?Sized> MapLike for LinearMapInner<K, V, S> {
+    type Key = K;
+    type Value = V;
+    fn get(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+        self.insert(key, value)
+    }
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.remove(key)
+    }
+}
+impl<K: Hash + Eq, V, const N: usize, S: BuildHasher> MapLike for FixedHashMap<K, V, N, S> {
+    type Key = K;
+    type Value = V;
+    fn get(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+        self.insert(key, value)
+    }
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.remove(key)
+    }
+}
+impl<K: Hash + Eq, V, const N: usize, S: BuildHasher> MapLike for FixedIndexMap<K, V, N, S> {
+    type Key = K;
+    type Value = V;
+    fn get(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+        self.insert(key, value)
+    }
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.remove(key)
+    }
+}
+impl<T, const N: usize> MapLike for SparseSet<T, N> {
+    type Key = usize;
+    type Value = T;
+    fn get(&self, id: &usize) -> Option<&T> {
+        self.get(*id)
+    }
+    fn get_mut(&mut self, id: &usize) -> Option<&mut T> {
+        self.get_mut(*id)
+    }
+    fn insert(&mut self, id: usize, value: T) -> Result<Option<T>, (usize, T)> {
+        self.insert(id, value)
+    }
+    fn remove(&mut self, id: &usize) -> Option<T> {
+        self.remove(*id)
+    }
+}
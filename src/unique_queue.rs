@@ -0,0 +1,207 @@
+//! Deduplicating FIFO
+
+use crate::fixed_hash_map::{
+    desired, make_hash, next, probe_distance, FnvBuildHasher, HashValue, EMPTY,
+};
+use crate::vec_deque::VecDeque;
+use core::fmt;
+use core::hash::Hash;
+use core::mem;
+
+#[derive(Clone, Copy)]
+struct Slot {
+    hash: HashValue,
+    /// Position in the queue, counted from the first push.
+    position: usize,
+}
+const EMPTY_SLOT: Slot = Slot {
+    hash: EMPTY,
+    position: 0,
+};
+
+/// FIFO that holds each value at most once, checked by linear scan.
+pub struct UniqueQueue<T, const N: usize> {
+    queue: VecDeque<T, N>,
+}
+impl<T, const N: usize> Default for UniqueQueue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> UniqueQueue<T, N> {
+    pub const fn new() -> Self {
+        UniqueQueue {
+            queue: VecDeque::new(),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+    pub fn is_full(&self) -> bool {
+        self.queue.is_full()
+    }
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+    pub fn front(&self) -> Option<&T> {
+        self.queue.get(0)
+    }
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.queue.as_slices()
+    }
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.queue.pop_front()
+    }
+}
+impl<T: PartialEq, const N: usize> UniqueQueue<T, N> {
+    pub fn contains(&self, value: &T) -> bool {
+        let (a, b) = self.queue.as_slices();
+        a.contains(value) || b.contains(value)
+    }
+    /// Returns `Ok(false)` if `value` is already queued.
+    pub fn push_back(&mut self, value: T) -> Result<bool, T> {
+        if self.contains(&value) {
+            return Ok(false);
+        }
+        self.queue.push_back(value)?;
+        Ok(true)
+    }
+}
+impl<T: fmt::Debug, const N: usize> fmt::Debug for UniqueQueue<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.queue, f)
+    }
+}
+
+/// FIFO that holds each value at most once, checked through a hash table.
+///
+/// The table stores a hash and a queue position per value rather than a
+/// copy of it, so `T` needs no `Clone`.
+pub struct HashedUniqueQueue<T, const N: usize> {
+    queue: VecDeque<T, N>,
+    indices: [Slot; N],
+    /// Values popped so far, positions are counted from the first push.
+    popped: usize,
+}
+impl<T, const N: usize> Default for HashedUniqueQueue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const N: usize> HashedUniqueQueue<T, N> {
+    pub const fn new() -> Self {
+        HashedUniqueQueue {
+            queue: VecDeque::new(),
+            indices: [EMPTY_SLOT; N],
+            popped: 0,
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+    pub fn is_full(&self) -> bool {
+        self.queue.is_full()
+    }
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.indices = [EMPTY_SLOT; N];
+        self.popped = 0;
+    }
+    pub fn front(&self) -> Option<&T> {
+        self.queue.get(0)
+    }
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.queue.as_slices()
+    }
+    fn insert_slot(&mut self, mut slot: Slot) {
+        let mut idx = desired(slot.hash, N);
+        let mut dist = 0;
+        loop {
+            if self.indices[idx].hash == EMPTY {
+                self.indices[idx] = slot;
+                return;
+            }
+            let existing = probe_distance(self.indices[idx].hash, idx, N);
+            if existing < dist {
+                mem::swap(&mut slot, &mut self.indices[idx]);
+                dist = existing;
+            }
+            idx = next(idx, N);
+            dist += 1;
+        }
+    }
+    fn remove_slot(&mut self, idx: usize) {
+        let mut hole = idx;
+        let mut after = next(hole, N);
+        while self.indices[after].hash != EMPTY
+            && probe_distance(self.indices[after].hash, after, N) > 0
+        {
+            self.indices[hole] = self.indices[after];
+            hole = after;
+            after = next(hole, N);
+        }
+        self.indices[hole] = EMPTY_SLOT;
+    }
+}
+impl<T: Hash + Eq, const N: usize> HashedUniqueQueue<T, N> {
+    fn find_slot(&self, hash: HashValue, value: &T) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut idx = desired(hash, N);
+        for dist in 0..N {
+            let slot = self.indices[idx];
+            if slot.hash == EMPTY || probe_distance(slot.hash, idx, N) < dist {
+                return None;
+            }
+            if slot.hash == hash && self.queue[slot.position.wrapping_sub(self.popped)] == *value {
+                return Some(idx);
+            }
+            idx = next(idx, N);
+        }
+        None
+    }
+    pub fn contains(&self, value: &T) -> bool {
+        self.find_slot(make_hash(&FnvBuildHasher, value), value)
+            .is_some()
+    }
+    /// Returns `Ok(false)` if `value` is already queued.
+    pub fn push_back(&mut self, value: T) -> Result<bool, T> {
+        let hash = make_hash(&FnvBuildHasher, &value);
+        if self.find_slot(hash, &value).is_some() {
+            return Ok(false);
+        }
+        self.queue.push_back(value)?;
+        let position = self.popped.wrapping_add(self.len() - 1);
+        self.insert_slot(Slot { hash, position });
+        Ok(true)
+    }
+    pub fn pop_front(&mut self) -> Option<T> {
+        let value = self.queue.pop_front()?;
+        let hash = make_hash(&FnvBuildHasher, &value);
+        let mut idx = desired(hash, N);
+        while self.indices[idx].position != self.popped || self.indices[idx].hash == EMPTY {
+            idx = next(idx, N);
+        }
+        self.remove_slot(idx);
+        self.popped = self.popped.wrapping_add(1);
+        Some(value)
+    }
+}
+impl<T: fmt::Debug, const N: usize> fmt::Debug for HashedUniqueQueue<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.queue, f)
+    }
+}
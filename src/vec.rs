@@ -1,30 +1,63 @@
+use crate::storage::Storage;
 use core::borrow::{Borrow, BorrowMut};
 use core::convert::{AsMut, AsRef, From};
 use core::fmt;
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use core::ops;
 use core::{ptr, slice};
 
-pub struct Vec<T, const N: usize> {
-    buf: MaybeUninit<[T; N]>,
+pub struct VecInner<T, S: Storage<T> + ?Sized> {
     len: usize,
+    _marker: PhantomData<T>,
+    buf: S,
+}
+pub type Vec<T, const N: usize> = VecInner<T, [MaybeUninit<T>; N]>;
+/// A `Vec` of any capacity, see [`Vec::as_view`].
+pub type VecView<T> = VecInner<T, [MaybeUninit<T>]>;
+/// A `Vec` in a buffer handed over at runtime.
+pub type BorrowedVec<'a, T> = VecInner<T, &'a mut [MaybeUninit<T>]>;
+
+impl<T, const N: usize> Default for Vec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 impl<T, const N: usize> Vec<T, N> {
-    const CAPACITY: usize = N;
+    const INIT: MaybeUninit<T> = MaybeUninit::uninit();
     pub const fn new() -> Self {
-        Vec {
-            buf: MaybeUninit::uninit(),
+        VecInner {
+            len: 0,
+            _marker: PhantomData,
+            buf: [Self::INIT; N],
+        }
+    }
+    pub fn as_view(&self) -> &VecView<T> {
+        self
+    }
+    pub fn as_mut_view(&mut self) -> &mut VecView<T> {
+        self
+    }
+}
+impl<T, S: Storage<T>> VecInner<T, S> {
+    /// Creates an empty `Vec` in `buf`, whatever it holds.
+    pub fn from_storage(buf: S) -> Self {
+        VecInner {
             len: 0,
+            _marker: PhantomData,
+            buf,
         }
     }
+}
+impl<T, S: Storage<T> + ?Sized> VecInner<T, S> {
     pub fn capacity(&self) -> usize {
-        Self::CAPACITY
+        self.buf.buffer().len()
     }
     pub fn as_ptr(&self) -> *const T {
-        self.buf.as_ptr() as *const T
+        self.buf.buffer().as_ptr() as *const T
     }
     pub fn as_mut_ptr(&mut self) -> *mut T {
-        self.buf.as_mut_ptr() as *mut T
+        self.buf.buffer_mut().as_mut_ptr() as *mut T
     }
     pub fn as_slice(&self) -> &[T] {
         self
@@ -46,6 +79,10 @@ impl<T, const N: usize> Vec<T, N> {
             ptr::drop_in_place(s);
         }
     }
+    /// # Safety
+    ///
+    /// `new_len` must be less than or equal to `capacity()`,
+    /// and the elements at `old_len..new_len` must be initialized.
     pub unsafe fn set_len(&mut self, new_len: usize) {
         debug_assert!(new_len <= self.capacity());
 
@@ -85,7 +122,7 @@ impl<T, const N: usize> Vec<T, N> {
         }
     }
 }
-impl<T: Clone, const N: usize> Vec<T, N> {
+impl<T: Clone, S: Storage<T> + ?Sized> VecInner<T, S> {
     pub fn extend_from_slice(&mut self, other: &[T]) {
         assert!(self.len + other.len() <= self.capacity());
 
@@ -96,7 +133,7 @@ impl<T: Clone, const N: usize> Vec<T, N> {
         }
     }
 }
-impl<T, const N: usize> ops::Deref for Vec<T, N> {
+impl<T, S: Storage<T> + ?Sized> ops::Deref for VecInner<T, S> {
     type Target = [T];
 
     fn deref(&self) -> &[T] {
@@ -104,7 +141,7 @@ impl<T, const N: usize> ops::Deref for Vec<T, N> {
     }
 }
 
-impl<T, const N: usize> ops::DerefMut for Vec<T, N> {
+impl<T, S: Storage<T> + ?Sized> ops::DerefMut for VecInner<T, S> {
     fn deref_mut(&mut self) -> &mut [T] {
         unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
     }
@@ -116,32 +153,42 @@ impl<T: Clone, const N: usize> From<&[T]> for Vec<T, N> {
         vec
     }
 }
-impl<T, const N: usize> AsRef<[T]> for Vec<T, N> {
+impl<T, S: Storage<T> + ?Sized> AsRef<[T]> for VecInner<T, S> {
     fn as_ref(&self) -> &[T] {
         self
     }
 }
-impl<T, const N: usize> AsMut<[T]> for Vec<T, N> {
+impl<T, S: Storage<T> + ?Sized> AsMut<[T]> for VecInner<T, S> {
     fn as_mut(&mut self) -> &mut [T] {
         self
     }
 }
-impl<T, const N: usize> Borrow<[T]> for Vec<T, N> {
+impl<T, S: Storage<T> + ?Sized> Borrow<[T]> for VecInner<T, S> {
     fn borrow(&self) -> &[T] {
         &self[..]
     }
 }
-impl<T, const N: usize> BorrowMut<[T]> for Vec<T, N> {
+impl<T, S: Storage<T> + ?Sized> BorrowMut<[T]> for VecInner<T, S> {
     fn borrow_mut(&mut self) -> &mut [T] {
         &mut self[..]
     }
 }
-impl<T: fmt::Debug, const N: usize> fmt::Debug for Vec<T, N> {
+/// Fails without writing anything if `s` does not fit.
+impl<S: Storage<u8> + ?Sized> fmt::Write for VecInner<u8, S> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.len + s.len() > self.capacity() {
+            return Err(fmt::Error);
+        }
+        self.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+impl<T: fmt::Debug, S: Storage<T> + ?Sized> fmt::Debug for VecInner<T, S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&**self, f)
     }
 }
-impl<T, const N: usize> Drop for Vec<T, N> {
+impl<T, S: Storage<T> + ?Sized> Drop for VecInner<T, S> {
     fn drop(&mut self) {
         self.clear();
     }
@@ -1,30 +1,65 @@
+use crate::storage::Storage;
 use core::fmt;
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use core::ops::{Index, IndexMut};
 use core::{ptr, slice};
 
-pub struct VecDeque<T, const N: usize> {
-    buf: MaybeUninit<[T; N]>,
+pub struct VecDequeInner<T, S: Storage<T> + ?Sized> {
     end: usize,
     //Tail always points to the first element
     start: usize,
     is_full: bool,
+    _marker: PhantomData<T>,
+    buf: S,
+}
+pub type VecDeque<T, const N: usize> = VecDequeInner<T, [MaybeUninit<T>; N]>;
+/// A `VecDeque` of any capacity, see [`VecDeque::as_view`].
+pub type VecDequeView<T> = VecDequeInner<T, [MaybeUninit<T>]>;
+/// A `VecDeque` in a buffer handed over at runtime.
+pub type BorrowedVecDeque<'a, T> = VecDequeInner<T, &'a mut [MaybeUninit<T>]>;
+
+impl<T, const N: usize> Default for VecDeque<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 impl<T, const N: usize> VecDeque<T, N> {
-    const CAPACITY: usize = N;
+    const INIT: MaybeUninit<T> = MaybeUninit::uninit();
     pub const fn new() -> Self {
-        VecDeque {
-            buf: MaybeUninit::uninit(),
+        VecDequeInner {
+            end: 0,
+            start: 0,
+            is_full: false,
+            _marker: PhantomData,
+            buf: [Self::INIT; N],
+        }
+    }
+    pub fn as_view(&self) -> &VecDequeView<T> {
+        self
+    }
+    pub fn as_mut_view(&mut self) -> &mut VecDequeView<T> {
+        self
+    }
+}
+impl<T, S: Storage<T>> VecDequeInner<T, S> {
+    /// Creates an empty `VecDeque` in `buf`, whatever it holds.
+    pub fn from_storage(buf: S) -> Self {
+        VecDequeInner {
             end: 0,
             start: 0,
             is_full: false,
+            _marker: PhantomData,
+            buf,
         }
     }
+}
+impl<T, S: Storage<T> + ?Sized> VecDequeInner<T, S> {
     fn ptr(&self) -> *mut T {
-        self.buf.as_ptr() as *mut T
+        self.buf.buffer().as_ptr() as *mut T
     }
     pub fn capacity(&self) -> usize {
-        Self::CAPACITY
+        self.buf.buffer().len()
     }
     pub fn len(&self) -> usize {
         let start = self.start;
@@ -94,7 +129,7 @@ impl<T, const N: usize> VecDeque<T, N> {
             )
         } else {
             (
-                unsafe { slice::from_raw_parts(ptr.add(self.start), N - self.start) },
+                unsafe { slice::from_raw_parts(ptr.add(self.start), self.capacity() - self.start) },
                 unsafe { slice::from_raw_parts(ptr, self.end) },
             )
         }
@@ -108,7 +143,9 @@ impl<T, const N: usize> VecDeque<T, N> {
             )
         } else {
             (
-                unsafe { slice::from_raw_parts_mut(ptr.add(self.start), N - self.start) },
+                unsafe {
+                    slice::from_raw_parts_mut(ptr.add(self.start), self.capacity() - self.start)
+                },
                 unsafe { slice::from_raw_parts_mut(ptr, self.end) },
             )
         }
@@ -146,7 +183,7 @@ impl<T, const N: usize> VecDeque<T, N> {
         }
     }
     pub fn push_front(&mut self, value: T) -> Result<(), T> {
-        if self.is_full() {
+        if self.len() == self.capacity() {
             return Err(value);
         }
 
@@ -158,7 +195,7 @@ impl<T, const N: usize> VecDeque<T, N> {
         Ok(())
     }
     pub fn push_back(&mut self, value: T) -> Result<(), T> {
-        if self.is_full() {
+        if self.len() == self.capacity() {
             return Err(value);
         }
 
@@ -170,7 +207,7 @@ impl<T, const N: usize> VecDeque<T, N> {
         Ok(())
     }
 }
-impl<T, const N: usize> Index<usize> for VecDeque<T, N> {
+impl<T, S: Storage<T> + ?Sized> Index<usize> for VecDequeInner<T, S> {
     type Output = T;
 
     #[inline]
@@ -179,18 +216,18 @@ impl<T, const N: usize> Index<usize> for VecDeque<T, N> {
     }
 }
 
-impl<T, const N: usize> IndexMut<usize> for VecDeque<T, N> {
+impl<T, S: Storage<T> + ?Sized> IndexMut<usize> for VecDequeInner<T, S> {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut T {
         self.get_mut(index).expect("Out of bounds access")
     }
 }
-impl<T: fmt::Debug, const N: usize> fmt::Debug for VecDeque<T, N> {
+impl<T: fmt::Debug, S: Storage<T> + ?Sized> fmt::Debug for VecDequeInner<T, S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Debug::fmt(&self.as_slices(), f)
     }
 }
-impl<T, const N: usize> Drop for VecDeque<T, N> {
+impl<T, S: Storage<T> + ?Sized> Drop for VecDequeInner<T, S> {
     fn drop(&mut self) {
         self.clear()
     }
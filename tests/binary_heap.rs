@@ -0,0 +1,69 @@
+use fixed_queue::binary_heap::{Max, Min, PeekMut};
+use fixed_queue::BinaryHeap;
+
+static HEAP: BinaryHeap<u32, Max, 4> = BinaryHeap::new();
+
+#[test]
+fn test_base() {
+    assert!(HEAP.is_empty());
+
+    let mut heap: BinaryHeap<usize, Max, 3> = BinaryHeap::new();
+    assert_eq!(heap.capacity(), 3);
+    assert!(heap.is_empty());
+
+    assert!(heap.push(2).is_ok());
+    assert!(heap.push(3).is_ok());
+    assert!(heap.push(1).is_ok());
+    assert_eq!(heap.push(4), Err(4));
+    assert_eq!(heap.peek(), Some(&3));
+    assert_eq!(heap.pop(), Some(3));
+    assert_eq!(heap.pop(), Some(2));
+    assert!(heap.push(5).is_ok());
+    assert_eq!(heap.pop(), Some(5));
+    assert_eq!(heap.pop(), Some(1));
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn test_min() {
+    let mut heap: BinaryHeap<usize, Min, 8> = BinaryHeap::new();
+    for i in [5, 1, 7, 3, 2, 8, 6, 4] {
+        assert!(heap.push(i).is_ok());
+    }
+    assert_eq!(heap.peek(), Some(&1));
+    assert_eq!(heap.drain_sorted().take(3).collect::<Vec<_>>(), [1, 2, 3]);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_peek_mut() {
+    let mut heap: BinaryHeap<usize, Max, 4> = BinaryHeap::new();
+    for i in [1, 5, 3] {
+        assert!(heap.push(i).is_ok());
+    }
+    *heap.peek_mut().unwrap() = 0;
+    assert_eq!(heap.peek(), Some(&3));
+    assert_eq!(PeekMut::pop(heap.peek_mut().unwrap()), 3);
+    assert_eq!(heap.len(), 2);
+    assert_eq!(heap.into_sorted_vec()[..], [0, 1]);
+
+    let mut heap: BinaryHeap<usize, Min, 4> = BinaryHeap::new();
+    for i in [2, 4, 1, 3] {
+        assert!(heap.push(i).is_ok());
+    }
+    assert_eq!(heap.into_sorted_vec()[..], [4, 3, 2, 1]);
+}
+
+#[test]
+fn test_drop() {
+    use std::rc::Rc;
+
+    let value = Rc::new(1);
+    let mut heap: BinaryHeap<_, Max, 3> = BinaryHeap::new();
+    assert!(heap.push(value.clone()).is_ok());
+    assert!(heap.push(value.clone()).is_ok());
+    drop(heap.pop());
+    assert_eq!(Rc::strong_count(&value), 2);
+    drop(heap);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
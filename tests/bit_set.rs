@@ -0,0 +1,49 @@
+use fixed_queue::BitSet;
+
+// Two words, so values `0..64`.
+const PINS: BitSet<2> = BitSet::from_bits(&[0, 5, 33]);
+
+#[test]
+fn test_base() {
+    let mut set: BitSet<2> = BitSet::new();
+    assert_eq!(set.capacity(), 64);
+    assert!(set.is_empty());
+
+    assert_eq!(set.insert(1), Ok(true));
+    assert_eq!(set.insert(1), Ok(false));
+    assert!(set.contains(1));
+    assert_eq!(set.insert(63), Ok(true));
+    assert_eq!(set.insert(64), Err(64));
+    assert!(!set.contains(64));
+    assert_eq!(set.count_ones(), 2);
+    assert!(set.remove(1));
+    assert!(!set.remove(1));
+    assert!(!set.contains(1));
+    assert_eq!(set.iter().collect::<Vec<_>>(), [63]);
+    assert_eq!(set.first(), Some(63));
+}
+
+#[test]
+fn test_algebra() {
+    assert!(PINS.contains(33));
+    assert_eq!(PINS.iter().collect::<Vec<_>>(), [0, 5, 33]);
+
+    let other = BitSet::<2>::from_bits(&[5, 6, 40]);
+    assert_eq!(
+        PINS.union(&other).iter().collect::<Vec<_>>(),
+        [0, 5, 6, 33, 40]
+    );
+    assert_eq!(PINS.intersection(&other).iter().collect::<Vec<_>>(), [5]);
+    assert_eq!(PINS.difference(&other).iter().collect::<Vec<_>>(), [0, 33]);
+    assert_eq!(
+        PINS.symmetric_difference(&other).iter().collect::<Vec<_>>(),
+        [0, 6, 33, 40]
+    );
+    assert!(!PINS.is_disjoint(&other));
+    assert!(PINS.intersection(&other).is_subset(&PINS));
+    assert!(PINS.is_superset(&BitSet::from_bits(&[33])));
+
+    let mut set = PINS;
+    set.difference_with(&PINS);
+    assert!(set.is_empty());
+}
@@ -0,0 +1,74 @@
+use fixed_queue::bounded_map::{Callback, EvictionPolicy, Fifo, Insertion, Lfu, Lru, Random};
+use fixed_queue::BoundedMap;
+
+static MAP: BoundedMap<u32, u32, Fifo, 4> = BoundedMap::new(Fifo::new());
+
+#[test]
+fn test_base() {
+    assert!(MAP.is_empty());
+
+    let mut map: BoundedMap<usize, usize, Fifo, 3> = BoundedMap::new(Fifo::new());
+    assert_eq!(map.capacity(), 3);
+    assert!(map.is_empty());
+
+    assert_eq!(map.insert(1, 1), Insertion::Inserted);
+    assert_eq!(map.insert(2, 2), Insertion::Inserted);
+    assert_eq!(map.insert(3, 3), Insertion::Inserted);
+    assert_eq!(map.insert(1, 10), Insertion::Replaced(1));
+    assert_eq!(map.get(&1), Some(&10));
+    assert_eq!(map.insert(4, 4), Insertion::Evicted(1, 10));
+    assert_eq!(map.insert(5, 5), Insertion::Evicted(2, 2));
+    assert_eq!(map.remove(&3), Some(3));
+    assert_eq!(map.insert(6, 6), Insertion::Inserted);
+    assert_eq!(map.insert(7, 7), Insertion::Evicted(4, 4));
+}
+
+#[test]
+fn test_policy() {
+    let mut lru: BoundedMap<usize, usize, Lru, 2> = BoundedMap::new(Lru::new());
+    lru.insert(1, 1);
+    lru.insert(2, 2);
+    lru.get(&1);
+    assert_eq!(lru.insert(3, 3), Insertion::Evicted(2, 2));
+
+    let mut lfu: BoundedMap<usize, usize, Lfu, 2> = BoundedMap::new(Lfu::new());
+    lfu.insert(1, 1);
+    lfu.insert(2, 2);
+    lfu.get(&2);
+    lfu.get(&2);
+    lfu.get(&1);
+    assert_eq!(lfu.insert(3, 3), Insertion::Evicted(1, 1));
+
+    let mut random: BoundedMap<usize, usize, Random, 2> = BoundedMap::new(Random::new(1));
+    random.insert(1, 1);
+    random.insert(2, 2);
+    assert!(matches!(random.insert(3, 3), Insertion::Evicted(..)));
+    assert_eq!(random.len(), 2);
+
+    let largest =
+        |entries: &[(usize, usize)]| (0..entries.len()).max_by_key(|i| entries[*i].1).unwrap();
+    let mut callback: BoundedMap<usize, usize, _, 2> = BoundedMap::new(Callback(largest));
+    callback.insert(1, 9);
+    callback.insert(2, 1);
+    assert_eq!(callback.insert(3, 3), Insertion::Evicted(1, 9));
+}
+
+#[test]
+fn test_random_zero_seed() {
+    let entries = [(0usize, 0usize); 4];
+    let meta = [(); 4];
+    let mut zero = Random::new(0);
+    let victims: Vec<usize> = (0..8).map(|_| zero.victim(&entries, &meta)).collect();
+    assert!(victims.iter().any(|i| *i != 0));
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut map: BoundedMap<usize, _, Fifo, 3> = BoundedMap::new(Fifo::new());
+    let (item, token) = OnDrop::token(1);
+    assert!(matches!(map.insert(1, item), Insertion::Inserted));
+    drop(map);
+    assert!(token.is_droped());
+}
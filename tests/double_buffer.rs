@@ -0,0 +1,74 @@
+use fixed_queue::{DoubleBuffer, TripleBuffer};
+use std::sync::Mutex;
+
+static SHARED: Mutex<DoubleBuffer<u8, 4>> = Mutex::new(DoubleBuffer::new());
+
+#[test]
+fn test_base() {
+    let mut buf: DoubleBuffer<usize, 3> = DoubleBuffer::new();
+    assert!(buf.read().is_empty());
+
+    assert!(buf.write().push(1).is_ok());
+    assert!(buf.write().push(2).is_ok());
+    assert!(buf.read().is_empty());
+    buf.swap();
+    assert_eq!(buf.read()[..], [1, 2]);
+    assert!(buf.write().is_empty());
+    let (read, write) = buf.split();
+    for x in read.iter() {
+        assert!(write.push(x * 10).is_ok());
+    }
+    read.clear();
+    buf.swap();
+    assert_eq!(buf.read()[..], [10, 20]);
+
+    SHARED.lock().unwrap().write().push(7).unwrap();
+    SHARED.lock().unwrap().swap();
+    assert_eq!(SHARED.lock().unwrap().read()[..], [7]);
+}
+
+#[test]
+fn test_triple() {
+    let mut buf: TripleBuffer<usize, 3> = TripleBuffer::new();
+    assert!(!buf.update());
+
+    assert!(buf.write().push(1).is_ok());
+    buf.publish();
+    buf.write().clear();
+    assert!(buf.write().push(2).is_ok());
+    buf.publish();
+    assert!(buf.update());
+    assert_eq!(buf.read()[..], [2]);
+    assert!(!buf.update());
+
+    buf.write().clear();
+    assert!(buf.write().push(3).is_ok());
+    assert_eq!(buf.read()[..], [2]);
+    buf.publish();
+    assert!(buf.update());
+    assert_eq!(buf.read()[..], [3]);
+}
+
+#[test]
+fn test_publish_clears() {
+    let mut buf: TripleBuffer<usize, 3> = TripleBuffer::new();
+    assert!(buf.write().push(1).is_ok());
+    buf.publish();
+    assert!(buf.write().push(2).is_ok());
+    buf.publish();
+    // Gets back the buffer that held 1, which was never read.
+    assert!(buf.write().is_empty());
+    assert!(buf.update());
+    assert_eq!(buf.read()[..], [2]);
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut buf: DoubleBuffer<_, 3> = DoubleBuffer::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(buf.write().push(item).is_ok());
+    drop(buf);
+    assert!(token.is_droped());
+}
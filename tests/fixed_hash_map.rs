@@ -0,0 +1,79 @@
+use fixed_queue::fixed_hash_map::Entry;
+use fixed_queue::FixedHashMap;
+
+static TABLE: FixedHashMap<u32, u32, 8> = FixedHashMap::new();
+
+#[test]
+fn test_base() {
+    assert!(TABLE.is_empty());
+
+    let mut map: FixedHashMap<usize, usize, 3> = FixedHashMap::new();
+    assert_eq!(map.capacity(), 3);
+    assert!(map.is_empty());
+
+    assert_eq!(map.insert(1, 1), Ok(None));
+    assert!(map.contains_key(&1));
+    assert_eq!(map.insert(1, 10), Ok(Some(1)));
+    assert_eq!(map.get(&1), Some(&10));
+    assert!(map.insert(2, 2).is_ok());
+    assert!(map.insert(3, 3).is_ok());
+    assert_eq!(map.insert(4, 4), Err((4, 4)));
+    assert!(!map.contains_key(&4));
+    assert_eq!(map.remove(&1), Some(10));
+    assert!(!map.contains_key(&1));
+    assert!(map.insert(5, 5).is_ok());
+    assert!(map.contains_key(&5));
+    *map.get_mut(&5).unwrap() += 1;
+    assert_eq!(map.get(&5), Some(&6));
+
+    let mut keys: Vec<_> = map.keys().copied().collect();
+    keys.sort();
+    assert_eq!(keys, [2, 3, 5]);
+}
+
+#[test]
+fn test_many() {
+    let mut map: FixedHashMap<u32, u32, 64> = FixedHashMap::new();
+    for i in 0..64 {
+        assert_eq!(map.insert(i * 7, i), Ok(None));
+    }
+    assert!(map.is_full());
+    for i in (0..64).step_by(2) {
+        assert_eq!(map.remove(&(i * 7)), Some(i));
+    }
+    for i in 0..64 {
+        assert_eq!(map.get(&(i * 7)).is_some(), i % 2 == 1);
+    }
+    for v in map.values_mut() {
+        *v += 100;
+    }
+    assert_eq!(map.values().filter(|v| **v >= 100).count(), 32);
+}
+
+#[test]
+fn test_entry() {
+    let mut map: FixedHashMap<&str, usize, 2> = FixedHashMap::new();
+    *map.entry("a").or_insert(0).unwrap() += 1;
+    *map.entry("a").or_insert(0).unwrap() += 1;
+    assert_eq!(map.get(&"a"), Some(&2));
+    map.entry("a").and_modify(|v| *v = 5);
+    assert_eq!(map.get(&"a"), Some(&5));
+    assert!(map.entry("b").or_default().is_ok());
+    assert_eq!(map.entry("c").or_insert(3), Err(("c", 3)));
+    match map.entry("b") {
+        Entry::Occupied(entry) => assert_eq!(entry.remove(), 0),
+        Entry::Vacant(_) => unreachable!(),
+    }
+    assert!(!map.contains_key(&"b"));
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut map: FixedHashMap<usize, _, 3> = FixedHashMap::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(map.insert(1, item).is_ok());
+    drop(map);
+    assert!(token.is_droped());
+}
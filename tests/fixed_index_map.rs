@@ -0,0 +1,74 @@
+use fixed_queue::FixedIndexMap;
+
+#[test]
+fn test_base() {
+    let mut map: FixedIndexMap<usize, usize, 3> = FixedIndexMap::new();
+    assert_eq!(map.capacity(), 3);
+    assert!(map.is_empty());
+
+    assert_eq!(map.insert(3, 30), Ok(None));
+    assert_eq!(map.insert(1, 10), Ok(None));
+    assert_eq!(map.insert(2, 20), Ok(None));
+    assert_eq!(map.insert(1, 11), Ok(Some(10)));
+    assert_eq!(map.insert(4, 40), Err((4, 40)));
+    assert_eq!(map[..], [(3, 30), (1, 11), (2, 20)]);
+    assert_eq!(map.get_index_of(&2), Some(2));
+    assert_eq!(map.get_index(0), Some((&3, &30)));
+
+    assert_eq!(map.swap_remove(&3), Some(30));
+    assert_eq!(map[..], [(2, 20), (1, 11)]);
+    assert_eq!(map.get_index_of(&2), Some(0));
+    assert_eq!(map.get(&1), Some(&11));
+    assert!(!map.contains_key(&3));
+    assert_eq!(map.swap_remove_index(0), Some((2, 20)));
+    assert_eq!(map.get_index_of(&1), Some(0));
+    assert!(map.insert(5, 50).is_ok());
+    *map.get_mut(&5).unwrap() += 1;
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 5]);
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), [11, 51]);
+}
+
+#[test]
+fn test_remove_order() {
+    let mut map: FixedIndexMap<char, usize, 5> = FixedIndexMap::new();
+    for (i, k) in "abcde".chars().enumerate() {
+        assert_eq!(map.insert(k, i), Ok(None));
+    }
+    assert_eq!(map.remove(&'b'), Some(1));
+    assert_eq!(map.remove_entry(&'d'), Some(('d', 3)));
+    assert_eq!(map.keys().collect::<String>(), "ace");
+    for (i, (k, _v)) in map.iter().enumerate() {
+        assert_eq!(map.get_index_of(k), Some(i));
+    }
+    assert_eq!(map.swap_remove_entry(&'a'), Some(('a', 0)));
+    assert_eq!(map.keys().collect::<String>(), "ec");
+    assert_eq!(map.get(&'c'), Some(&2));
+}
+
+#[test]
+fn test_many() {
+    let mut map: FixedIndexMap<u32, u32, 64> = FixedIndexMap::new();
+    for i in 0..64 {
+        assert_eq!(map.insert(i * 13, i), Ok(None));
+    }
+    for i in (0..64).step_by(3) {
+        assert_eq!(map.remove(&(i * 13)), Some(i));
+    }
+    for i in 0..64 {
+        assert_eq!(map.get(&(i * 13)), if i % 3 == 0 { None } else { Some(&i) });
+    }
+    for (i, (k, _v)) in map.iter().enumerate() {
+        assert_eq!(map.get_index_of(k), Some(i));
+    }
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut map: FixedIndexMap<usize, _, 3> = FixedIndexMap::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(map.insert(1, item).is_ok());
+    drop(map);
+    assert!(token.is_droped());
+}
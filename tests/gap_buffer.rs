@@ -0,0 +1,91 @@
+use fixed_queue::string::CapacityError;
+use fixed_queue::{GapBuffer, GapString};
+
+#[test]
+fn test_base() {
+    let mut buf: GapBuffer<u8, 5> = GapBuffer::new();
+    assert_eq!(buf.capacity(), 5);
+    assert!(buf.is_empty());
+    assert!(buf.delete_backward().is_none());
+    assert!(buf.delete_forward().is_none());
+
+    assert!(buf.insert(1).is_ok());
+    assert!(buf.insert(2).is_ok());
+    assert!(buf.insert(3).is_ok());
+    assert_eq!(buf.cursor(), 3);
+    assert!(buf.move_left());
+    assert!(buf.move_left());
+    assert!(buf.insert(9).is_ok());
+    assert_eq!(buf.as_slices(), (&[1, 9][..], &[2, 3][..]));
+    assert_eq!(buf.insert_slice(&[7, 8]), Err(CapacityError));
+    assert!(buf.insert(8).is_ok());
+    assert!(buf.is_full());
+    assert_eq!(buf.insert(0), Err(0));
+
+    assert_eq!(buf.delete_forward(), Some(2));
+    assert_eq!(buf.delete_backward(), Some(8));
+    assert_eq!(buf.iter().copied().collect::<Vec<_>>(), [1, 9, 3]);
+    assert_eq!(buf.get(2), Some(&3));
+    *buf.get_mut(0).unwrap() = 0;
+    buf.set_cursor(3);
+    assert!(!buf.move_right());
+    assert_eq!(buf.as_slices(), (&[0, 9, 3][..], &[][..]));
+    buf.set_cursor(0);
+    assert!(!buf.move_left());
+    assert!(buf.insert_slice(&[5, 6]).is_ok());
+    assert_eq!(buf.as_slices(), (&[5, 6][..], &[0, 9, 3][..]));
+    buf.clear();
+    assert!(buf.is_empty());
+    assert_eq!(buf.cursor(), 0);
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut buf: GapBuffer<_, 3> = GapBuffer::new();
+    let (item1, token1) = OnDrop::token(1);
+    let (item2, token2) = OnDrop::token(2);
+    assert!(buf.insert(item1).is_ok());
+    assert!(buf.insert(item2).is_ok());
+    assert!(buf.move_left());
+    drop(buf);
+    assert!(token1.is_droped());
+    assert!(token2.is_droped());
+}
+
+#[test]
+fn test_string() {
+    use core::fmt::Write;
+
+    let mut s: GapString<8> = GapString::new();
+    assert!(s.insert_str("héllo").is_ok());
+    assert_eq!(s.len(), 6);
+    assert!(s.move_left());
+    assert!(s.move_left());
+    assert!(s.move_left());
+    assert!(s.move_left());
+    assert_eq!(s.cursor(), 1);
+    assert_eq!(s.delete_forward(), Some('é'));
+    assert!(s.insert('e').is_ok());
+    assert_eq!(s.as_strs(), ("he", "llo"));
+    assert!(write!(s, "y ").is_ok());
+    assert_eq!(s.insert('€'), Err('€'));
+    assert_eq!(s.remaining(), 1);
+    assert_eq!(s.delete_backward(), Some(' '));
+    assert!(s.insert('ß').is_ok());
+    assert_eq!(s.chars().next_back(), Some('o'));
+    assert_eq!(std::format!("{}", s), "heyßllo");
+    assert!(s.move_left());
+    assert_eq!(s.cursor(), 3);
+    s.set_cursor(0);
+    assert_eq!(s.as_strs(), ("", "heyßllo"));
+}
+
+#[test]
+#[should_panic]
+fn test_string_boundary() {
+    let mut s: GapString<4> = GapString::new();
+    assert!(s.insert('é').is_ok());
+    s.set_cursor(1);
+}
@@ -0,0 +1,68 @@
+use fixed_queue::IndexedPriorityQueue;
+
+#[test]
+fn test_base() {
+    let mut queue: IndexedPriorityQueue<u32, 4> = IndexedPriorityQueue::new();
+    assert_eq!(queue.capacity(), 4);
+    assert!(queue.is_empty());
+
+    assert!(queue.push(0, 30).is_ok());
+    assert!(queue.push(1, 10).is_ok());
+    assert!(queue.push(2, 20).is_ok());
+    assert_eq!(queue.push(2, 5), Err(5));
+    assert_eq!(queue.push(4, 5), Err(5));
+    assert!(queue.contains(2));
+    assert!(!queue.contains(3));
+    assert_eq!(queue.peek(), Some((1, &10)));
+
+    assert_eq!(queue.change_priority(0, 1), Some(30));
+    assert_eq!(queue.peek(), Some((0, &1)));
+    assert_eq!(queue.remove(0), Some(1));
+    assert_eq!(queue.remove(0), None);
+    assert!(queue.push_or_decrease(2, 15));
+    assert!(!queue.push_or_decrease(2, 16));
+    assert!(queue.push_or_decrease(3, 12));
+    assert_eq!(queue.pop(), Some((1, 10)));
+    assert_eq!(queue.pop(), Some((3, 12)));
+    assert_eq!(queue.pop(), Some((2, 15)));
+    assert_eq!(queue.pop(), None);
+}
+
+#[test]
+fn test_dijkstra() {
+    const INF: u32 = u32::MAX;
+    let edges: [&[(usize, u32)]; 5] = [
+        &[(1, 4), (2, 1)],
+        &[(3, 1)],
+        &[(1, 2), (3, 5)],
+        &[(4, 3)],
+        &[],
+    ];
+    let mut dist = [INF; 5];
+    let mut queue: IndexedPriorityQueue<u32, 5> = IndexedPriorityQueue::new();
+    dist[0] = 0;
+    assert!(queue.push(0, 0).is_ok());
+    while let Some((node, d)) = queue.pop() {
+        for (next, w) in edges[node] {
+            if d + w < dist[*next] {
+                dist[*next] = d + w;
+                queue.push_or_decrease(*next, d + w);
+            }
+        }
+    }
+    assert_eq!(dist, [0, 3, 1, 4, 7]);
+}
+
+#[test]
+fn test_drop() {
+    use std::rc::Rc;
+
+    let priority = Rc::new(1);
+    let mut queue: IndexedPriorityQueue<_, 3> = IndexedPriorityQueue::new();
+    assert!(queue.push(0, priority.clone()).is_ok());
+    assert!(queue.push(1, priority.clone()).is_ok());
+    drop(queue.remove(0));
+    assert_eq!(Rc::strong_count(&priority), 2);
+    drop(queue);
+    assert_eq!(Rc::strong_count(&priority), 1);
+}
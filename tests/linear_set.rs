@@ -1,4 +1,4 @@
-use fixed_queue::LinearSet;
+use fixed_queue::{linear_set, LinearSet};
 
 #[test]
 fn test_base() {
@@ -32,3 +32,35 @@ fn test_drop() {
     drop(set);
     assert!(token.is_droped());
 }
+
+#[test]
+fn test_algebra() {
+    let mut a: LinearSet<usize, 4> = LinearSet::new();
+    let mut b: LinearSet<usize, 3> = LinearSet::new();
+    assert!(a.extend_from([1, 2, 3]).is_ok());
+    assert!(b.extend_from([2, 3, 4]).is_ok());
+
+    let mut union: std::vec::Vec<_> = a.union(&b).copied().collect();
+    union.sort();
+    assert_eq!(union, [1, 2, 3, 4]);
+    let mut inter: std::vec::Vec<_> = a.intersection(&b).copied().collect();
+    inter.sort();
+    assert_eq!(inter, [2, 3]);
+    let difference: linear_set::Difference<'_, usize, 3> = a.difference(&b);
+    assert_eq!(difference.copied().collect::<std::vec::Vec<_>>(), [1]);
+    let mut sym: std::vec::Vec<_> = a.symmetric_difference(&b).copied().collect();
+    sym.sort();
+    assert_eq!(sym, [1, 4]);
+
+    assert!(!a.is_disjoint(&b));
+    assert!(!a.is_subset(&b));
+    a.retain(|x| *x != 1);
+    assert!(a.is_subset(&b));
+    assert!(b.is_superset(&a));
+    assert_eq!(a.take(&2), Some(2));
+    assert_eq!(a.take(&2), None);
+    assert_eq!(a.replace(3), Ok(Some(3)));
+    assert_eq!(a.replace(5), Ok(None));
+    assert!(a.is_disjoint(&LinearSet::<usize, 1>::new()));
+    assert_eq!(b.extend_from([5]), Err(5));
+}
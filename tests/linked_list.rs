@@ -0,0 +1,127 @@
+use fixed_queue::LinkedList;
+
+#[test]
+fn test_base() {
+    let mut list: LinkedList<usize, 4> = LinkedList::new();
+    assert_eq!(list.capacity(), 4);
+    assert!(list.is_empty());
+
+    let b = list.push_back(2).unwrap();
+    let a = list.push_front(1).unwrap();
+    let d = list.push_back(4).unwrap();
+    let c = list.insert_after(b, 3).unwrap();
+    assert_eq!(list.push_back(5), Err(5));
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(list.remove(b), Some(2));
+    assert_eq!(list.remove(b), None);
+    assert_eq!(list.get(c), Some(&3));
+    assert_eq!(list.next_handle(a), Some(c));
+    assert_eq!(list.prev_handle(d), Some(c));
+    assert!(list.insert_before(a, 0).is_ok());
+    assert_eq!(list.front(), Some(&0));
+    assert_eq!(list.back(), Some(&4));
+    assert_eq!(list.pop_back(), Some(4));
+    assert_eq!(list.pop_front(), Some(0));
+    for x in list.iter_mut() {
+        *x *= 10;
+    }
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [10, 30]);
+}
+
+#[test]
+fn test_cursor() {
+    let mut list: LinkedList<usize, 8> = LinkedList::new();
+    for i in 0..5 {
+        assert!(list.push_back(i).is_ok());
+    }
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&mut 1));
+    assert_eq!(cursor.remove_current(), Some(1));
+    assert_eq!(cursor.current(), Some(&mut 2));
+    assert!(cursor.insert_before(7).is_ok());
+    assert_eq!(cursor.peek_prev(), Some(&mut 7));
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.peek_next(), Some(&mut 0));
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 7, 2, 3, 4]);
+}
+
+#[test]
+fn test_sort() {
+    let mut list: LinkedList<(u32, char), 8> = LinkedList::new();
+    let mut handles = Vec::new();
+    for item in [
+        (3, 'a'),
+        (1, 'b'),
+        (2, 'c'),
+        (1, 'd'),
+        (5, 'e'),
+        (0, 'f'),
+        (3, 'g'),
+    ] {
+        handles.push(list.push_back(item).unwrap());
+    }
+    list.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(list.iter().map(|x| x.1).collect::<String>(), "fbdcage");
+    assert_eq!(list.get(handles[4]), Some(&(5, 'e')));
+
+    let mut backward = String::new();
+    let mut handle = list.back_handle();
+    while let Some(h) = handle {
+        backward.push(list.get(h).unwrap().1);
+        handle = list.prev_handle(h);
+    }
+    assert_eq!(backward, "egacdbf");
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut list: LinkedList<_, 3> = LinkedList::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(list.push_back(item).is_ok());
+    drop(list);
+    assert!(token.is_droped());
+}
+
+#[test]
+fn test_stale_handle() {
+    let mut list: LinkedList<usize, 2> = LinkedList::new();
+    let a = list.push_back(1).unwrap();
+    assert_eq!(list.remove(a), Some(1));
+    let b = list.push_back(2).unwrap();
+    assert_ne!(a, b);
+    assert_eq!(list.get(a), None);
+    assert_eq!(list.remove(a), None);
+    assert_eq!(list.insert_after(a, 3), Err(3));
+    assert_eq!(list.cursor_mut(a).current(), None);
+    assert_eq!(list.get(b), Some(&2));
+}
+
+#[test]
+fn test_sort_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+    let mut list: LinkedList<_, 4> = LinkedList::new();
+    for i in [3, 1, 2] {
+        assert!(list.push_back((i, value.clone())).is_ok());
+    }
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        list.sort_by(|a, b| {
+            if a.0 == 2 || b.0 == 2 {
+                panic!("compare");
+            }
+            a.0.cmp(&b.0)
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(list.iter().map(|x| x.0).collect::<Vec<_>>(), [3, 1, 2]);
+    drop(list);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
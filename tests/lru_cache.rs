@@ -0,0 +1,50 @@
+use fixed_queue::bounded_map::Insertion;
+use fixed_queue::LruCache;
+
+static CACHE: LruCache<u32, u32, 4> = LruCache::new();
+
+#[test]
+fn test_base() {
+    assert!(CACHE.is_empty());
+
+    let mut cache: LruCache<usize, usize, 3> = LruCache::new();
+    assert_eq!(cache.capacity(), 3);
+    assert!(cache.is_empty());
+
+    assert_eq!(cache.put(1, 10), Insertion::Inserted);
+    assert_eq!(cache.put(2, 20), Insertion::Inserted);
+    assert_eq!(cache.put(3, 30), Insertion::Inserted);
+    assert_eq!(cache.get(&1), Some(&10));
+    assert_eq!(cache.peek(&2), Some(&20));
+    assert_eq!(cache.put(4, 40), Insertion::Evicted(2, 20));
+    assert!(!cache.contains(&2));
+    assert_eq!(
+        cache.iter().map(|(k, _v)| *k).collect::<Vec<_>>(),
+        [4, 1, 3]
+    );
+    assert_eq!(cache.put(3, 31), Insertion::Replaced(30));
+    assert_eq!(cache.peek_mru(), Some((&3, &31)));
+    assert_eq!(cache.peek_lru(), Some((&1, &10)));
+    assert_eq!(cache.pop(&4), Some(40));
+    assert_eq!(cache.pop_lru(), Some((1, 10)));
+    assert_eq!(cache.pop_lru(), Some((3, 31)));
+    assert_eq!(cache.pop_lru(), None);
+}
+
+#[test]
+fn test_zero_capacity() {
+    let mut cache: LruCache<usize, usize, 0> = LruCache::new();
+    assert_eq!(cache.put(1, 10), Insertion::Rejected(1, 10));
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut cache: LruCache<usize, _, 3> = LruCache::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(matches!(cache.put(1, item), Insertion::Inserted));
+    drop(cache);
+    assert!(token.is_droped());
+}
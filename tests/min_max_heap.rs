@@ -0,0 +1,72 @@
+use fixed_queue::MinMaxHeap;
+
+#[test]
+fn test_base() {
+    let mut heap: MinMaxHeap<usize, 3> = MinMaxHeap::new();
+    assert_eq!(heap.capacity(), 3);
+    assert!(heap.is_empty());
+
+    assert!(heap.push(2).is_ok());
+    assert_eq!(heap.peek_min(), Some(&2));
+    assert_eq!(heap.peek_max(), Some(&2));
+    assert!(heap.push(3).is_ok());
+    assert!(heap.push(1).is_ok());
+    assert_eq!(heap.push(4), Err(4));
+    assert_eq!(heap.peek_min(), Some(&1));
+    assert_eq!(heap.peek_max(), Some(&3));
+    assert_eq!(heap.pop_max(), Some(3));
+    assert_eq!(heap.pop_min(), Some(1));
+    assert_eq!(heap.pop_min(), Some(2));
+    assert_eq!(heap.pop_max(), None);
+}
+
+#[test]
+fn test_order() {
+    let mut heap: MinMaxHeap<u32, 64> = MinMaxHeap::new();
+    let mut x = 7u32;
+    let mut all = Vec::new();
+    for _ in 0..64 {
+        x = x.wrapping_mul(1103515245).wrapping_add(12345) % 1000;
+        all.push(x);
+        assert!(heap.push(x).is_ok());
+    }
+    all.sort();
+    for i in 0..32 {
+        assert_eq!(heap.pop_min(), Some(all[i]));
+        assert_eq!(heap.pop_max(), Some(all[63 - i]));
+    }
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_top_k() {
+    let mut heap: MinMaxHeap<u32, 3> = MinMaxHeap::new();
+    for i in [5, 1, 9, 3, 7, 2, 8] {
+        heap.push_or_replace_min(i);
+    }
+    assert_eq!(heap.pop_min(), Some(7));
+    assert_eq!(heap.pop_min(), Some(8));
+    assert_eq!(heap.pop_min(), Some(9));
+
+    for i in [5, 1, 9] {
+        assert!(heap.push(i).is_ok());
+    }
+    assert_eq!(heap.push_pop_max(10), 10);
+    assert_eq!(heap.push_pop_max(0), 9);
+    assert_eq!(heap.peek_min(), Some(&0));
+    assert_eq!(heap.peek_max(), Some(&5));
+}
+
+#[test]
+fn test_drop() {
+    use std::rc::Rc;
+
+    let value = Rc::new(1);
+    let mut heap: MinMaxHeap<_, 3> = MinMaxHeap::new();
+    assert!(heap.push(value.clone()).is_ok());
+    assert!(heap.push(value.clone()).is_ok());
+    drop(heap.pop_max());
+    assert_eq!(Rc::strong_count(&value), 2);
+    drop(heap);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,56 @@
+#![cfg(target_has_atomic = "ptr")]
+
+use fixed_queue::pool::PoolBox;
+use fixed_queue::Pool;
+
+static POOL: Pool<[u8; 4], 2> = Pool::new();
+
+#[test]
+fn test_base() {
+    assert_eq!(POOL.capacity(), 2);
+
+    let mut a = POOL.alloc([1; 4]).unwrap();
+    let b = POOL.alloc([2; 4]).unwrap();
+    assert_eq!(POOL.alloc([3; 4]).unwrap_err(), [3; 4]);
+    a[0] = 9;
+    assert_eq!(*a, [9, 1, 1, 1]);
+    assert_eq!(*b, [2; 4]);
+    drop(a);
+    let c = POOL.alloc([4; 4]).unwrap();
+    assert_eq!(PoolBox::into_inner(b), [2; 4]);
+    assert_eq!(*c, [4; 4]);
+    assert!(POOL.alloc([5; 4]).is_ok());
+}
+
+#[test]
+fn test_threads() {
+    static POOL: Pool<usize, 4> = Pool::new();
+
+    let handles: Vec<_> = (0..4)
+        .map(|t| {
+            std::thread::spawn(move || {
+                for i in 0..1000 {
+                    if let Ok(value) = POOL.alloc(t * 1000 + i) {
+                        assert_eq!(*value, t * 1000 + i);
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let all: Vec<_> = (0..4).map(|i| POOL.alloc(i).unwrap()).collect();
+    assert_eq!(all.len(), 4);
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let pool: Pool<_, 3> = Pool::new();
+    let (item, token) = OnDrop::token(1);
+    let boxed = pool.alloc(item).ok().unwrap();
+    drop(boxed);
+    assert!(token.is_droped());
+}
@@ -0,0 +1,54 @@
+use fixed_queue::PriorityFifo;
+
+static FIFO: PriorityFifo<u32, 4, 8> = PriorityFifo::new();
+
+#[test]
+fn test_base() {
+    assert!(FIFO.is_empty());
+
+    let mut fifo: PriorityFifo<usize, 3, 2> = PriorityFifo::new();
+    assert_eq!(fifo.capacity(), 6);
+    assert!(fifo.is_empty());
+
+    assert!(fifo.push(0, 1).is_ok());
+    assert!(fifo.push(2, 2).is_ok());
+    assert!(fifo.push(1, 3).is_ok());
+    assert!(fifo.push(2, 4).is_ok());
+    assert_eq!(fifo.push(2, 5), Err(5));
+    assert_eq!(fifo.push(3, 6), Err(6));
+    assert_eq!(fifo.top_priority(), Some(2));
+    assert_eq!(fifo.peek(), Some(&2));
+    assert_eq!(fifo.pop(), Some(2));
+    assert_eq!(fifo.pop_with_priority(), Some((2, 4)));
+    assert_eq!(fifo.pop(), Some(3));
+    assert!(fifo.push(2, 7).is_ok());
+    assert_eq!(fifo.pop(), Some(7));
+    assert_eq!(fifo.pop(), Some(1));
+    assert_eq!(fifo.pop(), None);
+    assert_eq!(fifo.top_priority(), None);
+}
+
+#[test]
+fn test_budget() {
+    let mut fifo: PriorityFifo<usize, 4, 4> = PriorityFifo::with_budget(5);
+    assert_eq!(fifo.capacity(), 5);
+    for i in 0..5 {
+        assert!(fifo.push(i % 4, i).is_ok());
+    }
+    assert!(fifo.is_full());
+    assert_eq!(fifo.push(0, 5), Err(5));
+    assert_eq!(fifo.pop(), Some(3));
+    assert!(fifo.push(0, 5).is_ok());
+    assert_eq!(fifo.level(0).unwrap().len(), 3);
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut fifo: PriorityFifo<_, 2, 3> = PriorityFifo::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(fifo.push(1, item).is_ok());
+    drop(fifo);
+    assert!(token.is_droped());
+}
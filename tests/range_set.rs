@@ -0,0 +1,108 @@
+use fixed_queue::string::CapacityError;
+use fixed_queue::{RangeMap, RangeSet};
+
+#[test]
+fn test_set() {
+    let mut set: RangeSet<u32, 3> = RangeSet::new();
+    assert_eq!(set.capacity(), 3);
+    assert!(set.insert(10..20).is_ok());
+    assert!(set.insert(30..40).is_ok());
+    assert!(set.insert(5..5).is_ok());
+    assert_eq!(set[..], [10..20, 30..40]);
+    // adjacent and overlapping ranges merge
+    assert!(set.insert(20..25).is_ok());
+    assert!(set.insert(24..31).is_ok());
+    assert_eq!(set.len(), 1);
+    assert_eq!(set.first(), Some(&(10..40)));
+    assert!(set.insert(0..2).is_ok());
+    assert!(set.insert(50..60).is_ok());
+    assert!(set.is_full());
+    assert_eq!(set.insert(45..46), Err(CapacityError));
+    assert!(set.insert(1..55).is_ok());
+    assert_eq!(set.len(), 1);
+    assert_eq!(set.first(), Some(&(0..60)));
+
+    assert!(set.contains(&0));
+    assert!(set.contains(&59));
+    assert!(!set.contains(&60));
+    assert!(set.overlaps(&(59..70)));
+    assert!(!set.overlaps(&(60..70)));
+    assert!(!set.overlaps(&(10..10)));
+}
+
+#[test]
+fn test_set_remove() {
+    let mut set: RangeSet<u32, 2> = RangeSet::new();
+    assert!(set.insert(0..100).is_ok());
+    assert!(set.remove(90..120).is_ok());
+    assert!(set.remove(10..20).is_ok());
+    assert_eq!(set[..], [0..10, 20..90]);
+    // a second split would need a third slot
+    assert_eq!(set.remove(40..50), Err(CapacityError));
+    assert_eq!(set[..], [0..10, 20..90]);
+    assert!(set.remove(5..30).is_ok());
+    assert_eq!(set[..], [0..5, 30..90]);
+    assert!(set.remove(0..5).is_ok());
+    assert!(set.remove(40..50).is_ok());
+    assert_eq!(set[..], [30..40, 50..90]);
+    assert!(set.remove(0..100).is_ok());
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_gaps() {
+    let mut set: RangeSet<u32, 4> = RangeSet::new();
+    assert!(set.insert(10..20).is_ok());
+    assert!(set.insert(30..40).is_ok());
+    let gaps: Vec<_> = set.gaps(0..50).collect();
+    assert_eq!(gaps, [0..10, 20..30, 40..50]);
+    let gaps: Vec<_> = set.gaps(15..35).collect();
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps.first(), Some(&(20..30)));
+    let gaps: Vec<_> = set.gaps(12..18).collect();
+    assert!(gaps.is_empty());
+    let gaps: Vec<_> = set.gaps(22..28).collect();
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps.first(), Some(&(22..28)));
+}
+
+#[test]
+fn test_map() {
+    let mut map: RangeMap<u32, char, 3> = RangeMap::new();
+    assert!(map.insert(0..10, 'a').is_ok());
+    assert!(map.insert(10..20, 'a').is_ok());
+    assert_eq!(map[..], [(0..20, 'a')]);
+    assert!(map.insert(5..8, 'b').is_ok());
+    assert_eq!(map[..], [(0..5, 'a'), (5..8, 'b'), (8..20, 'a')]);
+    assert_eq!(map.insert(12..14, 'c'), Err(CapacityError));
+    assert_eq!(map.get(&6), Some(&'b'));
+    assert_eq!(map.get_key_value(&9), Some((&(8..20), &'a')));
+    assert_eq!(map.get(&20), None);
+
+    assert!(map.insert(4..9, 'a').is_ok());
+    assert_eq!(map[..], [(0..20, 'a')]);
+    assert!(map.insert(15..30, 'b').is_ok());
+    assert!(map.remove(10..12).is_ok());
+    assert_eq!(map[..], [(0..10, 'a'), (12..15, 'a'), (15..30, 'b')]);
+    assert_eq!(map.remove(20..22), Err(CapacityError));
+    assert!(map.remove(14..16).is_ok());
+    assert_eq!(map[..], [(0..10, 'a'), (12..14, 'a'), (16..30, 'b')]);
+    assert!(map.overlaps(&(13..15)));
+    assert!(!map.overlaps(&(14..16)));
+    let gaps: Vec<_> = map.gaps(0..40).collect();
+    assert_eq!(gaps, [10..12, 14..16, 30..40]);
+}
+
+#[test]
+fn test_drop() {
+    use std::rc::Rc;
+
+    let mut map: RangeMap<u32, Rc<u32>, 3> = RangeMap::new();
+    let value = Rc::new(0);
+    assert!(map.insert(0..10, value.clone()).is_ok());
+    assert!(map.insert(5..8, Rc::new(1)).is_ok());
+    assert!(map.remove(0..2).is_ok());
+    assert_eq!(Rc::strong_count(&value), 3);
+    drop(map);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
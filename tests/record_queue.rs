@@ -0,0 +1,70 @@
+use fixed_queue::string::CapacityError;
+use fixed_queue::RecordQueue;
+
+fn joined((a, b): (&[u8], &[u8])) -> Vec<u8> {
+    [a, b].concat()
+}
+
+#[test]
+fn test_base() {
+    let mut queue: RecordQueue<16> = RecordQueue::new();
+    assert_eq!(queue.capacity(), 16);
+    assert!(queue.is_empty());
+    assert_eq!(queue.available(), 14);
+
+    assert!(queue.push(b"abc").is_ok());
+    assert!(queue.push(b"").is_ok());
+    assert!(queue.push(b"defgh").is_ok());
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.push(b"ij"), Err(CapacityError));
+    assert!(queue.push(b"").is_ok());
+    assert_eq!(queue.available(), 0);
+
+    assert_eq!(queue.peek().map(joined), Some(b"abc".to_vec()));
+    assert_eq!(queue.pop().map(joined), Some(b"abc".to_vec()));
+    assert_eq!(queue.pop().map(joined), Some(b"".to_vec()));
+    assert_eq!(
+        queue.iter().map(joined).collect::<Vec<_>>(),
+        [b"defgh".to_vec(), b"".to_vec()]
+    );
+}
+
+#[test]
+fn test_wrap() {
+    let mut queue: RecordQueue<10> = RecordQueue::new();
+    assert!(queue.push(b"ab").is_ok());
+    assert!(queue.push(b"c").is_ok());
+    assert_eq!(queue.pop().map(joined), Some(b"ab".to_vec()));
+    // Record wraps around the end.
+    assert!(queue.push(b"defgh").is_ok());
+    assert_eq!(queue.pop().map(joined), Some(b"c".to_vec()));
+    assert_eq!(queue.peek(), Some((&b"d"[..], &b"efgh"[..])));
+
+    let mut buf = [0; 4];
+    assert_eq!(queue.pop_into(&mut buf), Err(CapacityError));
+    let mut buf = [0; 8];
+    assert_eq!(queue.pop_into(&mut buf), Ok(Some(5)));
+    assert_eq!(&buf[..5], b"defgh");
+    assert_eq!(queue.pop_into(&mut buf), Ok(None));
+
+    // Header wraps around the end.
+    assert!(queue.push(b"abcde").is_ok());
+    assert!(queue.push(b"").is_ok());
+    assert_eq!(queue.pop().map(joined), Some(b"abcde".to_vec()));
+    assert!(queue.push(b"xyz").is_ok());
+    assert_eq!(queue.pop().map(joined), Some(b"".to_vec()));
+    assert_eq!(queue.pop(), Some((&b"xyz"[..], &b""[..])));
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_header_room() {
+    let mut queue: RecordQueue<8> = RecordQueue::new();
+    // Leaves one byte, too little for another header.
+    assert!(queue.push(b"abcde").is_ok());
+    assert_eq!(queue.available(), 0);
+    assert_eq!(queue.push(b""), Err(CapacityError));
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.pop().map(joined), Some(b"abcde".to_vec()));
+    assert!(queue.is_empty());
+}
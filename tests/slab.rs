@@ -0,0 +1,61 @@
+use fixed_queue::slab::{GenKey, SlabKey};
+use fixed_queue::Slab;
+
+static SLAB: Slab<u32, 4> = Slab::new();
+
+#[test]
+fn test_base() {
+    assert!(SLAB.is_empty());
+
+    let mut slab: Slab<usize, 3> = Slab::new();
+    assert_eq!(slab.capacity(), 3);
+    assert!(slab.is_empty());
+
+    let a = slab.insert(10).unwrap();
+    let b = slab.insert(20).unwrap();
+    let c = slab.insert(30).unwrap();
+    assert_eq!(slab.insert(40), Err(40));
+    assert_eq!(slab.remove(a), Some(10));
+    assert_eq!(slab.remove(a), None);
+    assert_eq!(slab.get(b), Some(&20));
+    assert_eq!(slab.get(c), Some(&30));
+    assert_eq!(slab.vacant_key(), Some(a));
+    let d = slab.insert(50).unwrap();
+    assert_eq!(d, a);
+    *slab.get_mut(d).unwrap() += 1;
+    assert_eq!(
+        slab.iter().map(|(_k, v)| *v).collect::<Vec<_>>(),
+        [51, 20, 30]
+    );
+    slab.clear();
+    assert!(slab.is_empty());
+    assert!(slab.insert(1).is_ok());
+}
+
+#[test]
+fn test_generation() {
+    let mut slab: Slab<usize, 2, GenKey> = Slab::new();
+    let a = slab.insert(1).unwrap();
+    assert_eq!(slab.remove(a), Some(1));
+    let b = slab.insert(2).unwrap();
+    assert_eq!(a.index(), b.index());
+    assert_ne!(a, b);
+    assert_eq!(slab.get(a), None);
+    assert_eq!(slab.remove(a), None);
+    assert_eq!(slab.get(b), Some(&2));
+    slab.clear();
+    let c = slab.insert(3).unwrap();
+    assert_eq!(slab.get(b), None);
+    assert_eq!(slab.get(c), Some(&3));
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut slab: Slab<_, 3> = Slab::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(slab.insert(item).is_ok());
+    drop(slab);
+    assert!(token.is_droped());
+}
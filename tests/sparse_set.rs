@@ -0,0 +1,43 @@
+use fixed_queue::SparseSet;
+
+#[test]
+fn test_base() {
+    let mut set: SparseSet<usize, 4> = SparseSet::new();
+    assert_eq!(set.capacity(), 4);
+    assert!(set.is_empty());
+
+    assert_eq!(set.insert(3, 30), Ok(None));
+    assert_eq!(set.insert(1, 10), Ok(None));
+    assert_eq!(set.insert(0, 0), Ok(None));
+    assert_eq!(set.insert(1, 11), Ok(Some(10)));
+    assert_eq!(set.insert(4, 40), Err((4, 40)));
+    assert!(set.contains(3));
+    assert!(!set.contains(2));
+    assert_eq!(set[..], [(3, 30), (1, 11), (0, 0)]);
+
+    assert_eq!(set.remove(3), Some(30));
+    assert_eq!(set.remove(3), None);
+    assert_eq!(set[..], [(0, 0), (1, 11)]);
+    assert_eq!(set.get(0), Some(&0));
+    assert_eq!(set.get_index(1), Some(1));
+    *set.get_mut(1).unwrap() += 1;
+    for value in set.values_mut() {
+        *value += 100;
+    }
+    assert_eq!(set.values().copied().collect::<Vec<_>>(), [100, 112]);
+    assert_eq!(set.ids().collect::<Vec<_>>(), [0, 1]);
+    set.clear();
+    assert!(!set.contains(0));
+    assert_eq!(set.insert(2, 2), Ok(None));
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut set: SparseSet<_, 3> = SparseSet::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(set.insert(1, item).is_ok());
+    drop(set);
+    assert!(token.is_droped());
+}
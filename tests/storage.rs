@@ -0,0 +1,67 @@
+use core::mem::MaybeUninit;
+use fixed_queue::{BorrowedHistory, BorrowedVec, BorrowedVecDeque, VecView};
+
+#[test]
+fn test_vec() {
+    fn total(vec: &VecView<u32>) -> u32 {
+        vec.iter().sum()
+    }
+
+    let mut buf = [MaybeUninit::uninit(); 3];
+    let mut vec = BorrowedVec::from_storage(&mut buf[..]);
+    assert_eq!(vec.capacity(), 3);
+    assert!(vec.push(1).is_ok());
+    assert!(vec.push(2).is_ok());
+    assert!(vec.push(3).is_ok());
+    assert_eq!(vec.push(4), Err(4));
+    assert_eq!(vec.pop(), Some(3));
+    assert_eq!(vec[..], [1, 2]);
+
+    let mut owned = fixed_queue::Vec::<u32, 2>::from_storage([MaybeUninit::uninit(); 2]);
+    assert!(owned.push(5).is_ok());
+    assert_eq!(total(&owned), 5);
+}
+
+#[test]
+fn test_vec_deque() {
+    let mut buf = [MaybeUninit::uninit(); 2];
+    let mut deque = BorrowedVecDeque::from_storage(&mut buf[..]);
+    assert!(deque.push_back(1).is_ok());
+    assert!(deque.push_front(0).is_ok());
+    assert!(deque.is_full());
+    assert_eq!(deque.push_back(2), Err(2));
+    assert_eq!(deque.pop_front(), Some(0));
+    assert_eq!(deque.pop_front(), Some(1));
+    assert_eq!(deque.pop_front(), None);
+
+    let mut empty = BorrowedVecDeque::from_storage(&mut [][..]);
+    assert_eq!(empty.push_back(1), Err(1));
+    assert_eq!(empty.push_front(1), Err(1));
+}
+
+#[test]
+fn test_history() {
+    let mut buf = [MaybeUninit::uninit(); 2];
+    let mut history = BorrowedHistory::from_storage(&mut buf[..]);
+    history.insert(1);
+    history.insert(2);
+    history.insert(3);
+    assert!(!history.contains(&1));
+    assert_eq!(history.capacity(), 2);
+
+    let mut empty = BorrowedHistory::from_storage(&mut [][..]);
+    empty.insert(1);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut buf = [MaybeUninit::uninit(), MaybeUninit::uninit()];
+    let (item, token) = OnDrop::token(1);
+    let mut vec = BorrowedVec::from_storage(&mut buf[..]);
+    assert!(vec.push(item).is_ok());
+    drop(vec);
+    assert!(token.is_droped());
+}
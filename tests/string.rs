@@ -0,0 +1,85 @@
+use core::convert::TryFrom;
+use core::fmt::Write;
+use fixed_queue::string::CapacityError;
+use fixed_queue::String;
+
+#[test]
+fn test_base() {
+    let mut s: String<8> = String::new();
+    assert_eq!(s.capacity(), 8);
+    assert!(s.is_empty());
+
+    assert!(s.push('a').is_ok());
+    assert!(s.push_str("bc").is_ok());
+    assert!(s.push('é').is_ok());
+    assert_eq!(s, "abcé");
+    assert_eq!(s.len(), 5);
+    assert_eq!(s.push_str("defg"), Err(CapacityError));
+    assert_eq!(s, "abcé");
+    assert!(s.push_str("def").is_ok());
+    assert_eq!(s.push('g'), Err('g'));
+    assert_eq!(s.pop(), Some('f'));
+    assert_eq!(s.pop(), Some('e'));
+    assert_eq!(s.pop(), Some('d'));
+    assert_eq!(s.pop(), Some('é'));
+    assert!(s.insert(1, 'ü').is_ok());
+    assert_eq!(s, "aübc");
+    assert_eq!(s.remove(1), 'ü');
+    assert!(s.insert_str(0, "xy").is_ok());
+    assert_eq!(s, "xyabc");
+    s.truncate(2);
+    assert_eq!(s, "xy");
+    s.retain(|c| c != 'x');
+    assert_eq!(s.to_uppercase(), "Y");
+}
+
+#[test]
+#[should_panic]
+fn test_truncate_boundary() {
+    let mut s: String<8> = String::try_from("é").unwrap();
+    s.truncate(1);
+}
+
+#[test]
+fn test_traits() {
+    let a: String<8> = String::try_from("abc").unwrap();
+    let b: String<4> = "abd".parse().unwrap();
+    assert!(String::<2>::try_from("abc").is_err());
+    assert!(a < b);
+    assert_eq!(a, a.clone());
+    assert_eq!(format!("{} {:?}", a, b), "abc \"abd\"");
+
+    let mut s: String<16> = String::new();
+    let tail = "ab";
+    write!(s, "{}-{}", 12, tail).unwrap();
+    assert_eq!(s, "12-ab");
+    assert!(write!(s, "{}", 1234567890123u64).is_err());
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let hash = |x: &dyn Fn(&mut DefaultHasher)| {
+        let mut h = DefaultHasher::new();
+        x(&mut h);
+        h.finish()
+    };
+    assert_eq!(hash(&|h| a.hash(h)), hash(&|h| "abc".hash(h)));
+}
+
+#[test]
+fn test_format() {
+    use fixed_queue::format::Truncating;
+    use fixed_queue::format_fixed;
+
+    let s = format_fixed!(8, "{}:{}", "a", 1).unwrap();
+    assert_eq!(s, "a:1");
+    assert!(format_fixed!(2, "{}", 123).is_err());
+
+    let mut s: String<4> = String::new();
+    let mut writer = Truncating::new(&mut s);
+    let e = 'é';
+    write!(writer, "ab{}", e).unwrap();
+    assert!(!writer.is_truncated());
+    write!(writer, "{}", e).unwrap();
+    assert!(writer.is_truncated());
+    assert_eq!(s, "abé");
+}
@@ -0,0 +1,45 @@
+use fixed_queue::TimerQueue;
+
+static TIMERS: TimerQueue<u32, 4> = TimerQueue::new();
+
+#[test]
+fn test_base() {
+    assert!(TIMERS.is_empty());
+
+    let mut timers: TimerQueue<&str, 4> = TimerQueue::new();
+    assert_eq!(timers.capacity(), 4);
+    assert!(timers.is_empty());
+
+    let a = timers.schedule(30, "a").unwrap();
+    let b = timers.schedule(10, "b").unwrap();
+    let c = timers.schedule(20, "c").unwrap();
+    let d = timers.schedule(40, "d").unwrap();
+    assert_eq!(timers.schedule(50, "e"), Err("e"));
+    assert_eq!(timers.next_deadline(), Some(10));
+    assert_eq!(timers.cancel(c), Some("c"));
+    assert_eq!(timers.cancel(c), None);
+    assert!(timers.reschedule(d, 5));
+    assert_eq!(timers.deadline(d), Some(5));
+
+    assert_eq!(timers.poll_expired(4).count(), 0);
+    assert_eq!(timers.poll_expired(15).collect::<Vec<_>>(), ["d", "b"]);
+    assert_eq!(timers.get(b), None);
+    assert_eq!(timers.get(a), Some(&"a"));
+
+    let e = timers.schedule(25, "e").unwrap();
+    assert_eq!(timers.pop_expired(100), Some((25, "e")));
+    assert_eq!(timers.cancel(e), None);
+    assert_eq!(timers.pop_expired(100), Some((30, "a")));
+    assert!(timers.is_empty());
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut timers: TimerQueue<_, 3> = TimerQueue::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(timers.schedule(1, item).is_ok());
+    drop(timers);
+    assert!(token.is_droped());
+}
@@ -0,0 +1,129 @@
+use fixed_queue::traits::{Fifo, FixedCapacity, Lifo, MapLike};
+use fixed_queue::{
+    FixedHashMap, History, LinearMap, LinearSet, LinkedList, Pool, PriorityFifo, SparseSet,
+    TripleBuffer, UniqueQueue, Vec, VecDeque,
+};
+
+fn drain_fifo<Q: Fifo<Item = u32>>(queue: &mut Q) -> std::vec::Vec<u32> {
+    let mut out = std::vec::Vec::new();
+    while let Some(item) = queue.dequeue() {
+        out.push(item);
+    }
+    out
+}
+
+fn fill_fifo<Q: Fifo<Item = u32>>(queue: &mut Q) {
+    let mut i = 0;
+    while queue.enqueue(i).is_ok() {
+        i += 1;
+    }
+    assert!(queue.is_full());
+    assert_eq!(queue.remaining(), 0);
+    assert_eq!(queue.front(), Some(&0));
+}
+
+#[test]
+fn test_fifo() {
+    let mut deque: VecDeque<u32, 3> = VecDeque::new();
+    fill_fifo(&mut deque);
+    assert_eq!(drain_fifo(&mut deque), [0, 1, 2]);
+
+    let mut list: LinkedList<u32, 3> = LinkedList::new();
+    fill_fifo(&mut list);
+    assert_eq!(drain_fifo(&mut list), [0, 1, 2]);
+
+    let mut unique: UniqueQueue<u32, 3> = UniqueQueue::new();
+    assert_eq!(unique.enqueue(7), Ok(()));
+    assert_eq!(unique.enqueue(7), Ok(()));
+    assert_eq!(FixedCapacity::len(&unique), 1);
+    assert_eq!(drain_fifo(&mut unique), [7]);
+
+    let mut priority: PriorityFifo<u32, 2, 4> = PriorityFifo::with_budget(3);
+    fill_fifo(&mut priority);
+    assert!(priority.push(1, 9).is_err());
+    assert_eq!(drain_fifo(&mut priority), [0, 1, 2]);
+}
+
+fn reverse<S: Lifo<Item = u32>>(stack: &mut S, items: &[u32]) -> std::vec::Vec<u32> {
+    for item in items {
+        assert!(stack.push(*item).is_ok());
+    }
+    assert_eq!(stack.top(), items.last());
+    let mut out = std::vec::Vec::new();
+    while let Some(item) = stack.pop() {
+        out.push(item);
+    }
+    out
+}
+
+#[test]
+fn test_lifo() {
+    let mut vec: Vec<u32, 3> = Vec::new();
+    assert_eq!(reverse(&mut vec, &[1, 2, 3]), [3, 2, 1]);
+    let mut deque: VecDeque<u32, 3> = VecDeque::new();
+    assert_eq!(reverse(&mut deque, &[1, 2, 3]), [3, 2, 1]);
+    let mut list: LinkedList<u32, 3> = LinkedList::new();
+    assert_eq!(reverse(&mut list, &[1, 2]), [2, 1]);
+}
+
+fn count<M: MapLike<Key = usize, Value = u32>>(map: &mut M, keys: &[usize]) {
+    for key in keys {
+        if let Some(value) = map.get_mut(key) {
+            *value += 1;
+        } else {
+            assert!(map.insert(*key, 1).is_ok());
+        }
+    }
+}
+
+#[test]
+fn test_map() {
+    let mut linear: LinearMap<usize, u32, 4> = LinearMap::new();
+    count(&mut linear, &[1, 2, 1, 3, 1]);
+    assert_eq!(MapLike::get(&linear, &1), Some(&3));
+    assert_eq!(MapLike::remove(&mut linear, &2), Some(1));
+    assert!(!MapLike::contains_key(&linear, &2));
+
+    let mut hash: FixedHashMap<usize, u32, 4> = FixedHashMap::new();
+    count(&mut hash, &[1, 2, 1, 3, 1]);
+    assert_eq!(MapLike::get(&hash, &1), Some(&3));
+    assert_eq!(FixedCapacity::remaining(&hash), 1);
+
+    let mut sparse: SparseSet<u32, 4> = SparseSet::new();
+    count(&mut sparse, &[1, 2, 1, 3, 1]);
+    assert_eq!(MapLike::get(&sparse, &1), Some(&3));
+    assert_eq!(MapLike::insert(&mut sparse, 9, 0), Err((9, 0)));
+}
+
+#[test]
+fn test_capacity() {
+    fn usage<C: FixedCapacity>(c: &C) -> (usize, usize) {
+        (c.len(), c.remaining())
+    }
+
+    let mut history: History<u32, 2> = History::new();
+    history.insert(1);
+    assert_eq!(usage(&history), (1, 1));
+    history.insert(2);
+    history.insert(3);
+    assert!(FixedCapacity::is_full(&history));
+
+    let mut set: LinearSet<u32, 3> = LinearSet::new();
+    assert!(set.insert(1).is_ok());
+    assert_eq!(usage(&set), (1, 2));
+
+    let string: fixed_queue::String<8> = "héllo".parse().unwrap();
+    assert_eq!(usage(&string), (6, 2));
+
+    let pool: Pool<u32, 2> = Pool::new();
+    let item = pool.alloc(1).ok().unwrap();
+    assert_eq!(usage(&pool), (1, 1));
+    drop(item);
+    assert_eq!(usage(&pool), (0, 2));
+
+    let mut buffer: TripleBuffer<u32, 2> = TripleBuffer::new();
+    assert!(buffer.write().push(1).is_ok());
+    assert_eq!(usage(&buffer), (1, 1));
+    buffer.publish();
+    assert_eq!(usage(&buffer), (0, 2));
+}
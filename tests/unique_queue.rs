@@ -0,0 +1,71 @@
+use fixed_queue::{HashedUniqueQueue, UniqueQueue};
+
+#[test]
+fn test_base() {
+    let mut queue: UniqueQueue<usize, 3> = UniqueQueue::new();
+    assert_eq!(queue.capacity(), 3);
+    assert!(queue.is_empty());
+
+    assert_eq!(queue.push_back(1), Ok(true));
+    assert_eq!(queue.push_back(1), Ok(false));
+    assert_eq!(queue.push_back(2), Ok(true));
+    assert_eq!(queue.push_back(3), Ok(true));
+    assert_eq!(queue.push_back(3), Ok(false));
+    assert_eq!(queue.push_back(4), Err(4));
+    assert!(queue.contains(&2));
+    assert_eq!(queue.pop_front(), Some(1));
+    assert!(!queue.contains(&1));
+    assert_eq!(queue.push_back(1), Ok(true));
+    assert_eq!(queue.pop_front(), Some(2));
+    assert_eq!(queue.pop_front(), Some(3));
+    assert_eq!(queue.pop_front(), Some(1));
+    assert_eq!(queue.pop_front(), None);
+}
+
+#[test]
+fn test_hashed() {
+    let mut queue: HashedUniqueQueue<usize, 3> = HashedUniqueQueue::new();
+    assert_eq!(queue.push_back(1), Ok(true));
+    assert_eq!(queue.push_back(1), Ok(false));
+    assert_eq!(queue.push_back(2), Ok(true));
+    assert_eq!(queue.push_back(3), Ok(true));
+    assert_eq!(queue.push_back(4), Err(4));
+    assert_eq!(queue.pop_front(), Some(1));
+    assert!(!queue.contains(&1));
+    assert!(queue.contains(&2));
+    assert_eq!(queue.push_back(1), Ok(true));
+    assert_eq!(queue.front(), Some(&2));
+    queue.clear();
+    assert!(!queue.contains(&2));
+}
+
+#[test]
+fn test_hashed_wrap() {
+    // Not `Clone`, the index only keeps hashes and positions.
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct Id(u32);
+
+    let mut queue: HashedUniqueQueue<Id, 4> = HashedUniqueQueue::new();
+    for i in 0..40 {
+        assert_eq!(queue.push_back(Id(i % 7)), Ok(true));
+        if queue.is_full() {
+            assert_eq!(queue.pop_front(), Some(Id((i - 3) % 7)));
+        }
+        assert!(queue.contains(&Id(i % 7)));
+        assert_eq!(queue.push_back(Id(i % 7)), Ok(false));
+    }
+    assert_eq!(queue.len(), 3);
+    assert!(queue.contains(&Id(38 % 7)));
+    assert!(!queue.contains(&Id(36 % 7)));
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut queue: UniqueQueue<_, 3> = UniqueQueue::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(queue.push_back(item).is_ok());
+    drop(queue);
+    assert!(token.is_droped());
+}
@@ -33,3 +33,20 @@ fn test_drop() {
     drop(vec);
     assert!(token.is_droped());
 }
+
+#[test]
+fn test_write() {
+    use core::fmt::Write;
+    use fixed_queue::format::Truncating;
+
+    let mut vec: Vec<u8, 8> = Vec::new();
+    write!(vec, "AT+{}", 12).unwrap();
+    assert_eq!(&vec[..], b"AT+12");
+    assert!(write!(vec, "{}", 1234).is_err());
+    assert_eq!(&vec[..], b"AT+12");
+
+    let mut writer = Truncating::new(&mut vec);
+    write!(writer, "{}", 1234).unwrap();
+    assert!(writer.is_truncated());
+    assert_eq!(&vec[..], b"AT+12123");
+}
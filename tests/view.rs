@@ -0,0 +1,84 @@
+use fixed_queue::{
+    History, HistoryView, LinearMap, LinearMapView, Vec, VecDeque, VecDequeView, VecView,
+};
+
+fn push_all(vec: &mut VecView<u32>, items: &[u32]) -> usize {
+    items
+        .iter()
+        .take_while(|item| vec.push(**item).is_ok())
+        .count()
+}
+
+#[test]
+fn test_vec() {
+    let mut small: Vec<u32, 2> = Vec::new();
+    let mut large: Vec<u32, 8> = Vec::new();
+    assert_eq!(push_all(&mut small, &[1, 2, 3]), 2);
+    assert_eq!(push_all(large.as_mut_view(), &[1, 2, 3]), 3);
+    let view: &VecView<u32> = &small;
+    assert_eq!(view.capacity(), 2);
+    assert_eq!(view[..], [1, 2]);
+    assert_eq!(large.as_view().capacity(), 8);
+
+    let views: [&VecView<u32>; 2] = [&small, &large];
+    assert_eq!(views.iter().map(|v| v.len()).sum::<usize>(), 5);
+}
+
+#[test]
+fn test_vec_deque() {
+    fn rotate(deque: &mut VecDequeView<u32>) {
+        if let Some(item) = deque.pop_front() {
+            assert!(deque.push_back(item).is_ok());
+        }
+    }
+
+    let mut deque: VecDeque<u32, 3> = VecDeque::new();
+    assert!(deque.push_back(1).is_ok());
+    assert!(deque.push_back(2).is_ok());
+    rotate(&mut deque);
+    assert_eq!(deque[0], 2);
+    assert_eq!(deque[1], 1);
+    assert_eq!(deque.as_view().capacity(), 3);
+}
+
+#[test]
+fn test_history() {
+    fn record(history: &mut HistoryView<u32>, value: u32) {
+        history.insert(value)
+    }
+
+    let mut history: History<u32, 2> = History::new();
+    record(&mut history, 1);
+    record(&mut history, 2);
+    record(&mut history, 3);
+    assert_eq!(history.as_view().capacity(), 2);
+    assert!(!history.contains(&1));
+}
+
+#[test]
+fn test_linear_map() {
+    fn bump(map: &mut LinearMapView<&'static str, u32>, key: &'static str) {
+        match map.get_mut(&key) {
+            Some(count) => *count += 1,
+            None => assert!(map.insert(key, 1).is_ok()),
+        }
+    }
+
+    let mut map: LinearMap<&str, u32, 4> = LinearMap::new();
+    bump(&mut map, "a");
+    bump(&mut map, "b");
+    bump(&mut map, "a");
+    assert_eq!(map.get(&"a"), Some(&2));
+    assert_eq!(map.as_view().capacity(), 4);
+}
+
+#[test]
+fn test_drop() {
+    use on_drop::OnDrop;
+
+    let mut vec: Vec<_, 3> = Vec::new();
+    let (item, token) = OnDrop::token(1);
+    assert!(vec.as_mut_view().push(item).is_ok());
+    vec.as_mut_view().clear();
+    assert!(token.is_droped());
+}